@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day01 {
+    changes: Vec<i64>,
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let changes = aoc::error::lines(input, |line| Ok(line.parse()?))?;
+        Ok(Self { changes })
+    }
+
+    fn part1(&self) -> anyhow::Result<i64> {
+        Ok(self.changes.iter().sum())
+    }
+
+    fn part2(&self) -> anyhow::Result<i64> {
+        part2(&self.changes)
+    }
+}
+
+fn part2(changes: &[i64]) -> anyhow::Result<i64> {
+    // Each pass shifts every frequency by the same drift, so two of them can only ever meet if
+    // they're the same mod the drift. Otherwise this would loop forever.
+    let drift: i64 = changes.iter().sum();
+    if drift != 0 {
+        let mut residues = HashSet::new();
+        let mut freq: i64 = 0;
+        let repeats = changes.iter().any(|d| {
+            let repeat = !residues.insert(freq.rem_euclid(drift));
+            freq += d;
+            repeat
+        });
+        if !repeats {
+            bail!("no frequency ever repeats");
+        }
+    }
+
+    let mut freqs = HashSet::new();
+    let mut freq = 0;
+    freqs.insert(freq);
+    loop {
+        for d in changes {
+            freq += d;
+            if !freqs.insert(freq) {
+                return Ok(freq);
+            }
+        }
+    }
+}
+
+aoc::example_tests! {
+    Day01,
+    part1 = "3",
+    part2 = "2",
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day02 {
+    ids: Vec<String>,
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let ids = aoc::lines(input).map(String::from).collect();
+        Ok(Self { ids })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        Ok(part1(&self.ids))
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        part2(&self.ids)
+    }
+}
+
+type CharCount = HashMap<char, u32>;
+
+fn count_chars(s: &str) -> CharCount {
+    let mut counts: CharCount = HashMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn has_double(cc: &CharCount) -> bool {
+    cc.values().any(|&v| v == 2)
+}
+
+fn has_triple(cc: &CharCount) -> bool {
+    cc.values().any(|&v| v == 3)
+}
+
+fn part1(ids: &[String]) -> u32 {
+    let mut twos = 0;
+    let mut threes = 0;
+    for id in ids {
+        let cc = count_chars(id);
+        if has_double(&cc) {
+            twos += 1;
+        }
+        if has_triple(&cc) {
+            threes += 1;
+        }
+    }
+    twos * threes
+}
+
+/// How many positions differ, for two IDs of the same length.
+fn difference_count(s1: &str, s2: &str) -> usize {
+    s1.chars().zip(s2.chars()).filter(|(a, b)| a != b).count()
+}
+
+fn common_chars(s1: &str, s2: &str) -> String {
+    s1.chars()
+        .zip(s2.chars())
+        .filter(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .collect()
+}
+
+fn part2(ids: &[String]) -> anyhow::Result<String> {
+    for (i, s1) in ids.iter().enumerate() {
+        for s2 in &ids[i + 1..] {
+            if s1.len() == s2.len() && difference_count(s1, s2) == 1 {
+                return Ok(common_chars(s1, s2));
+            }
+        }
+    }
+    bail!("no two IDs differ by exactly one character")
+}
+
+aoc::example_tests! {
+    Day02,
+    part1 = "12",
+    part2 = "abcde",
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day03 {
+    claims: Vec<Rectangle>,
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let claims = aoc::error::lines(input, Rectangle::parse)?;
+        Ok(Self { claims })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(&self.claims))
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        part2(&self.claims)
+    }
+}
+
+struct Rectangle {
+    id: String,
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+impl Rectangle {
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let (id, x, y, w, h) = aoc::scan!(line, "#{} @ {},{}: {}x{}", String, u32, u32, u32, u32)?;
+        Ok(Self { id, x, y, w, h })
+    }
+
+    fn squares(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (self.x..(self.x + self.w))
+            .flat_map(move |x| (self.y..(self.y + self.h)).map(move |y| (x, y)))
+    }
+}
+
+fn layers(claims: &[Rectangle]) -> HashMap<(u32, u32), u32> {
+    let mut squares = HashMap::new();
+    for r in claims {
+        for square in r.squares() {
+            *squares.entry(square).or_insert(0) += 1;
+        }
+    }
+    squares
+}
+
+fn part1(claims: &[Rectangle]) -> usize {
+    layers(claims)
+        .values()
+        .filter(|&&layers| layers > 1)
+        .count()
+}
+
+fn uncovered(grid: &HashMap<(u32, u32), u32>, r: &Rectangle) -> bool {
+    r.squares().all(|square| grid.get(&square) == Some(&1))
+}
+
+fn part2(claims: &[Rectangle]) -> anyhow::Result<String> {
+    let squares = layers(claims);
+    match claims.iter().find(|r| uncovered(&squares, r)) {
+        Some(r) => Ok(r.id.clone()),
+        None => bail!("every claim overlaps another one"),
+    }
+}
+
+aoc::example_tests! {
+    Day03,
+    part1 = "4",
+    part2 = "3",
+}
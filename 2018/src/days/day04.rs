@@ -0,0 +1,400 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail};
+use aoc::{Input, Solution};
+
+pub struct Day04 {
+    log: SleepLog,
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let log = SleepLog::parse(input)?;
+        Ok(Self { log })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        let Some((guard, _)) = self.log.most_asleep_guard() else {
+            bail!("nobody ever fell asleep");
+        };
+        let (minute, _) = self
+            .log
+            .sleepiest_minute(guard)
+            .expect("the sleepiest guard slept at some point");
+        Ok(guard * minute)
+    }
+
+    fn part2(&self) -> anyhow::Result<u32> {
+        let Some((guard, minute, _)) = self.log.most_frequent_minute() else {
+            bail!("nobody ever fell asleep");
+        };
+        Ok(guard * minute)
+    }
+}
+
+type Guard = u32;
+
+/// The only hour anyone sleeps in.
+const MINUTES: usize = 60;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        let (year, month, day) = aoc::scan!(s, "{}-{}-{}", u32, u32, u32)?;
+        if !(1..=12).contains(&month) {
+            bail!("no such month: {}", month);
+        }
+        if !(1..=days_in_month(year, month)).contains(&day) {
+            bail!("no such day: {}-{:02}-{:02}", year, month, day);
+        }
+        Ok(Self { year, month, day })
+    }
+
+    fn next(self) -> Self {
+        let Self { year, month, day } = self;
+        if day < days_in_month(year, month) {
+            Self {
+                day: day + 1,
+                ..self
+            }
+        } else if month < 12 {
+            Self {
+                month: month + 1,
+                day: 1,
+                ..self
+            }
+        } else {
+            Self {
+                year: year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Timestamp {
+    date: Date,
+    hour: u32,
+    minute: u32,
+}
+
+impl Timestamp {
+    /// Shifts can start a little before midnight, but they count for the next day.
+    fn shift_date(&self) -> Date {
+        if self.hour >= 12 {
+            self.date.next()
+        } else {
+            self.date
+        }
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Date { year, month, day } = self.date;
+        write!(
+            f,
+            "{}-{:02}-{:02} {:02}:{:02}",
+            year, month, day, self.hour, self.minute
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Event {
+    ShiftChange(Guard),
+    FallsAsleep,
+    WakesUp,
+}
+
+/// One line of the log, remembering where it was so errors can point back at it after sorting.
+#[derive(Debug, Clone)]
+struct Entry {
+    line: usize,
+    text: String,
+    timestamp: Timestamp,
+    event: Event,
+}
+
+impl Entry {
+    fn parse(line: &str) -> anyhow::Result<(Timestamp, Event)> {
+        let (date, hour, minute, event) =
+            aoc::scan!(line, "[{} {}:{}] {}", String, u32, u32, String)?;
+
+        let date = Date::parse(&date)?;
+        if hour >= 24 || minute >= 60 {
+            bail!("no such time: {:02}:{:02}", hour, minute);
+        }
+
+        let event = match event.as_str() {
+            "wakes up" => Event::WakesUp,
+            "falls asleep" => Event::FallsAsleep,
+            shift => Event::ShiftChange(aoc::scan!(shift, "Guard #{} begins shift", Guard)?),
+        };
+
+        Ok((Timestamp { date, hour, minute }, event))
+    }
+
+    fn error(&self, message: String) -> anyhow::Error {
+        aoc::Error::at_line(self.line, &self.text, anyhow!(message)).into()
+    }
+}
+
+/// One guard's night on duty, and which minutes of the midnight hour they slept through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Shift {
+    date: Date,
+    guard: Guard,
+    asleep: [bool; MINUTES],
+}
+
+/// Every shift in the log, in order, with each guard's sleep added up by minute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SleepLog {
+    shifts: Vec<Shift>,
+    histograms: BTreeMap<Guard, [u32; MINUTES]>,
+}
+
+impl SleepLog {
+    /// The lines can be in any order.
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut entries = vec![];
+        for (i, text) in aoc::lines(text).enumerate() {
+            let (timestamp, event) =
+                Entry::parse(text).map_err(|err| aoc::Error::at_line(i + 1, text, err))?;
+            entries.push(Entry {
+                line: i + 1,
+                text: text.to_string(),
+                timestamp,
+                event,
+            });
+        }
+
+        entries.sort_by_key(|e| (e.timestamp, e.line));
+        Self::from_entries(&entries)
+    }
+
+    fn from_entries(entries: &[Entry]) -> anyhow::Result<Self> {
+        let mut shifts: Vec<Shift> = vec![];
+        // When the guard on duty fell asleep (if they're asleep), and the entry that said so.
+        let mut asleep_since: Option<(usize, &Entry)> = None;
+
+        for entry in entries {
+            let ts = entry.timestamp;
+
+            if let Event::ShiftChange(guard) = entry.event {
+                if let Some((_, fell)) = asleep_since {
+                    return Err(fell.error(format!(
+                        "guard #{} fell asleep, but never woke up before #{} took over",
+                        shifts.last().expect("someone was asleep").guard,
+                        guard
+                    )));
+                }
+                shifts.push(Shift {
+                    date: ts.shift_date(),
+                    guard,
+                    asleep: [false; MINUTES],
+                });
+                continue;
+            }
+
+            let Some(shift) = shifts.last_mut() else {
+                return Err(entry.error(format!(
+                    "the log starts mid-shift: nobody's on duty at {}",
+                    ts
+                )));
+            };
+            if ts.date != shift.date || ts.hour != 0 {
+                return Err(entry.error(format!(
+                    "guard #{} can only sleep between 00:00 and 00:59 on {}-{:02}-{:02}",
+                    shift.guard, shift.date.year, shift.date.month, shift.date.day
+                )));
+            }
+
+            let minute = ts.minute as usize;
+            match (entry.event, asleep_since) {
+                (Event::FallsAsleep, None) => asleep_since = Some((minute, entry)),
+                (Event::WakesUp, Some((start, _))) => {
+                    shift.asleep[start..minute].fill(true);
+                    asleep_since = None;
+                }
+                (Event::FallsAsleep, Some(_)) => {
+                    return Err(entry.error(format!("guard #{} is already asleep", shift.guard)))
+                }
+                (Event::WakesUp, None) => {
+                    return Err(entry.error(format!("guard #{} isn't asleep", shift.guard)))
+                }
+                (Event::ShiftChange(_), _) => unreachable!(),
+            }
+        }
+
+        if let Some((_, fell)) = asleep_since {
+            let guard = shifts.last().expect("someone was asleep").guard;
+            return Err(fell.error(format!("guard #{} fell asleep, but never woke up", guard)));
+        }
+
+        let mut histograms = BTreeMap::new();
+        for shift in &shifts {
+            let histogram = histograms.entry(shift.guard).or_insert([0; MINUTES]);
+            for (count, &asleep) in histogram.iter_mut().zip(&shift.asleep) {
+                *count += u32::from(asleep);
+            }
+        }
+
+        Ok(Self { shifts, histograms })
+    }
+
+    /// How many nights the guard was asleep during each minute, if they were ever on duty.
+    pub fn histogram(&self, guard: Guard) -> Option<&[u32; MINUTES]> {
+        self.histograms.get(&guard)
+    }
+
+    /// The guard who slept the most minutes in total, and how many.
+    pub fn most_asleep_guard(&self) -> Option<(Guard, u32)> {
+        self.histograms
+            .iter()
+            .map(|(&guard, histogram)| (guard, histogram.iter().sum()))
+            .filter(|&(_, total)| total > 0)
+            .max_by_key(|&(_, total)| total)
+    }
+
+    /// The minute the guard was most often asleep during, and how many nights.
+    pub fn sleepiest_minute(&self, guard: Guard) -> Option<(u32, u32)> {
+        let histogram = self.histogram(guard)?;
+        sleepiest(histogram)
+    }
+
+    /// The guard and minute that were asleep together the most often, and how many nights.
+    pub fn most_frequent_minute(&self) -> Option<(Guard, u32, u32)> {
+        self.histograms
+            .iter()
+            .filter_map(|(&guard, histogram)| {
+                let (minute, count) = sleepiest(histogram)?;
+                Some((guard, minute, count))
+            })
+            .max_by_key(|&(_, _, count)| count)
+    }
+}
+
+fn sleepiest(histogram: &[u32; MINUTES]) -> Option<(u32, u32)> {
+    let (minute, &count) = histogram
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .max_by_key(|&(minute, &count)| (count, std::cmp::Reverse(minute)))?;
+    Some((minute as u32, count))
+}
+
+aoc::example_tests! {
+    Day04,
+    part1 = "240",
+    part2 = "4455",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up
+";
+
+    #[test]
+    fn queries() {
+        let log = SleepLog::parse(EXAMPLE).unwrap();
+        assert_eq!(log.shifts.len(), 5);
+        assert_eq!(log.shifts[1].date, Date::parse("1518-11-02").unwrap());
+
+        assert_eq!(log.most_asleep_guard(), Some((10, 50)));
+        assert_eq!(log.sleepiest_minute(10), Some((24, 2)));
+        assert_eq!(log.sleepiest_minute(99), Some((45, 3)));
+        assert_eq!(log.sleepiest_minute(7), None);
+        assert_eq!(log.most_frequent_minute(), Some((99, 45, 3)));
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut lines: Vec<&str> = EXAMPLE.lines().collect();
+        lines.reverse();
+        let shuffled = SleepLog::parse(&lines.join("\n")).unwrap();
+        assert_eq!(shuffled, SleepLog::parse(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn broken_logs() {
+        let err = |lines: &[&str]| SleepLog::parse(&lines.join("\n")).unwrap_err().to_string();
+
+        assert_eq!(
+            err(&[
+                "[1518-11-01 00:25] wakes up",
+                "[1518-11-01 00:05] falls asleep",
+            ]),
+            "line 2: the log starts mid-shift: nobody's on duty at 1518-11-01 00:05\n  | [1518-11-01 00:05] falls asleep"
+        );
+        assert_eq!(
+            err(&[
+                "[1518-11-01 00:00] Guard #10 begins shift",
+                "[1518-11-01 00:05] falls asleep",
+                "[1518-11-02 00:00] Guard #99 begins shift",
+            ]),
+            "line 2: guard #10 fell asleep, but never woke up before #99 took over\n  | [1518-11-01 00:05] falls asleep"
+        );
+        assert_eq!(
+            err(&[
+                "[1518-11-01 00:00] Guard #10 begins shift",
+                "[1518-11-01 00:25] wakes up",
+            ]),
+            "line 2: guard #10 isn't asleep\n  | [1518-11-01 00:25] wakes up"
+        );
+        assert_eq!(
+            err(&[
+                "[1518-11-01 00:00] Guard #10 begins shift",
+                "[1518-11-01 00:05] falls asleep",
+            ]),
+            "line 2: guard #10 fell asleep, but never woke up\n  | [1518-11-01 00:05] falls asleep"
+        );
+        assert_eq!(
+            err(&["[1518-02-29 00:00] Guard #10 begins shift"]),
+            "line 1: no such day: 1518-02-29\n  | [1518-02-29 00:00] Guard #10 begins shift"
+        );
+    }
+}
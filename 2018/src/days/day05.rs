@@ -0,0 +1,101 @@
+use aoc::{Input, Solution};
+
+pub struct Day05 {
+    polymer: Vec<u8>,
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let polymer = aoc::error::line(&[input.trim()], 0, parse)?;
+        Ok(Self { polymer })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(reduce(self.polymer.iter().copied()).len())
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(part2(&self.polymer))
+    }
+}
+
+fn parse(line: &str) -> anyhow::Result<Vec<u8>> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        let err = format!("expected a unit (a letter), found {:?}", c);
+        let column = line[..i].chars().count() + 1;
+        return Err(aoc::Error::new(err).with_column(column).into());
+    }
+    Ok(line.bytes().collect())
+}
+
+/// Units react when they're the same type (letter) with opposite polarity (case).
+fn reacts(a: u8, b: u8) -> bool {
+    a ^ b == b'a' ^ b'A'
+}
+
+/// Reacts everything that can react, in one pass: each unit either cancels out the last surviving
+/// unit or survives itself.
+fn reduce(units: impl Iterator<Item = u8>) -> Vec<u8> {
+    let mut stack = Vec::new();
+    for unit in units {
+        match stack.last() {
+            Some(&top) if reacts(top, unit) => {
+                stack.pop();
+            }
+            _ => stack.push(unit),
+        }
+    }
+    stack
+}
+
+fn part2(polymer: &[u8]) -> usize {
+    // Taking out a unit type never lets something react that couldn't already, so the fully
+    // reacted polymer is a (much shorter) place to start.
+    let reduced = reduce(polymer.iter().copied());
+
+    (b'a'..=b'z')
+        .filter(|&t| reduced.iter().any(|u| u.to_ascii_lowercase() == t))
+        .map(|t| {
+            let without = reduced
+                .iter()
+                .copied()
+                .filter(|u| u.to_ascii_lowercase() != t);
+            reduce(without).len()
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+aoc::example_tests! {
+    Day05,
+    part1 = "10",
+    part2 = "4",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reactions() {
+        assert_eq!(reduce("aA".bytes()), b"");
+        assert_eq!(reduce("abBA".bytes()), b"");
+        assert_eq!(reduce("abAB".bytes()), b"abAB");
+        assert_eq!(reduce("aabAAB".bytes()), b"aabAAB");
+        assert_eq!(reduce("dabAcCaCBAcCcaDA".bytes()), b"dabCBAcaDA");
+    }
+
+    #[test]
+    fn not_a_unit() {
+        let err = aoc::error::line(&["ab1c"], 0, parse).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a unit (a letter), found '1'\n  | ab1c\n  |   ^"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use aoc::Day;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+
+pub const ALL: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+];
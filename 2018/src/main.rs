@@ -1,18 +1,5 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-// mod day5; // TODO: optimize
+mod days;
 
-fn main() {
-    println!("Day 1 part 1: {}", day1::part1());
-    println!("Day 1 part 2: {}", day1::part2());
-    println!("Day 2 part 1: {}", day2::part1());
-    println!("Day 2 part 2: {}", day2::part2());
-    println!("Day 3 part 1: {}", day3::part1());
-    println!("Day 3 part 2: {}", day3::part2());
-    println!("Day 4 part 1: {}", day4::part1());
-    println!("Day 4 part 2: {}", day4::part2());
-    // println!("Day 5 part 1: {}", day5::part1()); // TODO: optimize
-    // println!("Day 5 part 2: {}", day5::part2()); // TODO: optimize
+fn main() -> anyhow::Result<()> {
+    aoc::runner::main(2018, days::ALL)
 }
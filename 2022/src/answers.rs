@@ -0,0 +1,118 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::{input::Source, solution::Part};
+
+/// The known-correct answers, shared by every year. See the comment at the top of the file.
+pub const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Answers for one day's input. Either part may be unknown (or not solved yet).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Answers for one day, by input.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Inputs {
+    real: Option<Expected>,
+    ex: Option<Expected>,
+}
+
+/// The contents of `answers.toml`, keyed by year, then day, then input (`real` or `ex`).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<u32, BTreeMap<u32, Inputs>>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        text.parse()
+            .with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// The expected answer, if one is recorded. Only the real and example inputs have answers.
+    pub fn get(&self, year: u32, day: u32, source: &Source, part: Part) -> Option<&str> {
+        let inputs = self.years.get(&year)?.get(&day)?;
+        let expected = match source {
+            Source::Real => inputs.real.as_ref(),
+            Source::Example => inputs.ex.as_ref(),
+            Source::Stdin | Source::Path(_) => None,
+        };
+        expected?.get(part)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let answers: Answers = r#"
+            [2022.1.ex]
+            part1 = "24000"
+            part2 = "45000"
+
+            [2022.25.real]
+            part1 = "2=-1=0"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            answers.get(2022, 1, &Source::Example, Part::One),
+            Some("24000")
+        );
+        assert_eq!(
+            answers.get(2022, 1, &Source::Example, Part::Two),
+            Some("45000")
+        );
+        assert_eq!(answers.get(2022, 1, &Source::Real, Part::One), None);
+        assert_eq!(
+            answers.get(2022, 25, &Source::Real, Part::One),
+            Some("2=-1=0")
+        );
+        assert_eq!(answers.get(2022, 25, &Source::Real, Part::Two), None);
+        assert_eq!(answers.get(2018, 1, &Source::Real, Part::One), None);
+        assert_eq!(answers.get(2022, 1, &Source::Stdin, Part::One), None);
+    }
+
+    #[test]
+    fn typos_are_errors() {
+        assert!("[2022.1.example]\npart1 = \"1\""
+            .parse::<Answers>()
+            .is_err());
+        assert!("[2022.1.ex]\npart3 = \"1\"".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn checked_in_file_parses() {
+        Answers::load(Path::new(ANSWERS_FILE)).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+//! A [`Grid`] of booleans, packed one bit per cell.
+//!
+//! For maps that are only ever "something here" or "nothing here", this takes a sixty-fourth of
+//! the memory of a `Grid<bool>` (or a `HashSet` of positions), so it's cheap to clone every step.
+
+use std::fmt::Display;
+
+use crate::grid::{Grid, RC};
+
+const BITS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    height: usize,
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// An empty grid: nothing set anywhere.
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            words: vec![0; (height * width).div_ceil(BITS)],
+        }
+    }
+
+    /// Parses a character map, one row per line, like [`Grid::parse`].
+    pub fn parse(
+        text: &str,
+        cell: impl FnMut(char) -> anyhow::Result<bool>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self::from(&Grid::parse(text, cell)?))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn in_bounds(&self, (r, c): RC) -> bool {
+        r < self.height && c < self.width
+    }
+
+    fn bit(&self, (r, c): RC) -> (usize, u64) {
+        let i = r * self.width + c;
+        (i / BITS, 1 << (i % BITS))
+    }
+
+    /// Whether the cell is set. Everything outside the grid counts as unset.
+    pub fn get(&self, rc: RC) -> bool {
+        if !self.in_bounds(rc) {
+            return false;
+        }
+        let (word, mask) = self.bit(rc);
+        self.words[word] & mask != 0
+    }
+
+    /// Sets the cell, and returns whether it was unset before (like [`HashSet::insert`]).
+    ///
+    /// [`HashSet::insert`]: std::collections::HashSet::insert
+    pub fn insert(&mut self, rc: RC) -> bool {
+        self.check(rc);
+        let (word, mask) = self.bit(rc);
+        let was_unset = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_unset
+    }
+
+    /// Unsets the cell, and returns whether it was set before.
+    pub fn remove(&mut self, rc: RC) -> bool {
+        self.check(rc);
+        let (word, mask) = self.bit(rc);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    fn check(&self, rc: RC) {
+        if !self.in_bounds(rc) {
+            panic!(
+                "{:?} is out of bounds for a {}x{} grid",
+                rc, self.height, self.width
+            );
+        }
+    }
+
+    /// How many cells are set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// The positions of every set cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = RC> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                let n = i * BITS + bit;
+                Some((n / self.width, n % self.width))
+            })
+        })
+    }
+
+    /// A bigger copy, with `margin` empty cells added on every side.
+    pub fn padded(&self, margin: usize) -> Self {
+        let mut grid = Self::new(self.height + 2 * margin, self.width + 2 * margin);
+        for (r, c) in self.iter() {
+            grid.insert((r + margin, c + margin));
+        }
+        grid
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.height(), grid.width());
+        for (rc, &set) in grid.iter() {
+            if set {
+                bits.insert(rc);
+            }
+        }
+        bits
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in 0..self.height {
+            for c in 0..self.width {
+                let chr = if self.get((r, c)) { '#' } else { '.' };
+                write!(f, "{}", chr)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+
+    const MAP: &str = "\
+#....#....
+.........#
+..........
+#.#.......
+";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = BitGrid::parse(MAP, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            other => bail!("unexpected {:?}", other),
+        })
+        .unwrap();
+
+        assert_eq!((grid.height(), grid.width()), (4, 10));
+        assert_eq!(grid.len(), 5);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 5), (1, 9), (3, 0), (3, 2)]
+        );
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        // Big enough to spill over into a second word.
+        let mut grid = BitGrid::new(9, 9);
+        assert!(grid.is_empty());
+
+        assert!(grid.insert((8, 8)));
+        assert!(!grid.insert((8, 8)));
+        assert!(grid.insert((0, 3)));
+        assert!(grid.get((8, 8)));
+        assert!(!grid.get((8, 7)));
+        assert!(!grid.get((9, 0)));
+        assert_eq!(grid.len(), 2);
+
+        assert!(grid.remove((8, 8)));
+        assert!(!grid.remove((8, 8)));
+        assert_eq!(grid.iter().collect::<Vec<_>>(), vec![(0, 3)]);
+
+        let padded = grid.padded(2);
+        assert_eq!((padded.height(), padded.width()), (13, 13));
+        assert_eq!(padded.iter().collect::<Vec<_>>(), vec![(2, 5)]);
+    }
+}
@@ -0,0 +1,334 @@
+//! Talking to the Advent of Code website (or anything that looks enough like it).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use crate::solution::Part;
+
+/// Where the session token (and maybe a different server) go. Not checked in!
+pub const CONFIG_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The site asks that automated requests say who's making them.
+const USER_AGENT: &str = "github.com/jdkaplan/advent-of-code by jdkaplan@metagram.net";
+
+/// Settings from `aoc.toml`, which the `AOC_SESSION` and `AOC_BASE_URL` environment variables
+/// override.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie from a logged-in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> anyhow::Result<Self> {
+        Self::load_from(Path::new(CONFIG_FILE), |var| std::env::var(var).ok())
+    }
+
+    /// A missing file is fine (everything might be in the environment), but a broken one isn't.
+    pub fn load_from(path: &Path, env: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let mut config = if path.exists() {
+            let text = std::fs::read_to_string(path)
+                .with_context(|| format!("reading {}", path.display()))?;
+            toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?
+        } else {
+            Self::default()
+        };
+
+        if let Some(session) = env("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> anyhow::Result<Self> {
+        let Some(session) = config.session.clone() else {
+            bail!(
+                "no session token: set AOC_SESSION or add `session = \"...\"` to {}",
+                CONFIG_FILE
+            );
+        };
+
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        })
+    }
+
+    fn url(&self, year: u32, day: u32, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> anyhow::Result<String> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call();
+        read(url, response)
+    }
+
+    /// The puzzle input for the logged-in user.
+    pub fn input(&self, year: u32, day: u32) -> anyhow::Result<String> {
+        let url = self.url(year, day, "/input");
+        self.get(&url).map_err(|err| match err.downcast_ref() {
+            Some(Status(400)) => err.context("the session token is missing or expired"),
+            Some(Status(404)) => err.context(format!("{} day {} isn't unlocked yet", year, day)),
+            _ => err,
+        })
+    }
+
+    /// Sends an answer, returning the page that says whether it was right.
+    pub fn answer(&self, year: u32, day: u32, part: Part, answer: &str) -> anyhow::Result<String> {
+        let url = self.url(year, day, "/answer");
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)]);
+        read(&url, response)
+    }
+}
+
+/// An unsuccessful HTTP status, so callers can tell "not found" from "no network".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Status(pub u16);
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP status {}", self.0)
+    }
+}
+
+impl std::error::Error for Status {}
+
+fn read(url: &str, response: Result<ureq::Response, ureq::Error>) -> anyhow::Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .with_context(|| format!("reading the response from {}", url)),
+        Err(ureq::Error::Status(code, _)) => {
+            Err(anyhow::Error::new(Status(code)).context(format!("requesting {}", url)))
+        }
+        Err(err) => Err(anyhow::Error::new(err).context(format!("requesting {}", url))),
+    }
+}
+
+/// Downloads the input into `dir`, unless there's already a copy there. Empty files (left over from
+/// pasting inputs by hand) don't count.
+pub fn fetch(client: &Client, year: u32, day: u32, dir: &Path) -> anyhow::Result<PathBuf> {
+    let path = dir.join(format!("day{:02}.txt", day));
+
+    let cached = std::fs::metadata(&path).is_ok_and(|meta| meta.len() > 0);
+    if cached {
+        bail!(
+            "{} already exists (delete it to download it again)",
+            path.display()
+        );
+    }
+
+    let text = client.input(year, day)?;
+
+    std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+
+    // Write it all or nothing, so a failure doesn't leave half an input behind.
+    let partial = path.with_extension("txt.part");
+    std::fs::write(&partial, text).with_context(|| format!("writing {}", partial.display()))?;
+    std::fs::rename(&partial, &path).with_context(|| format!("writing {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread::JoinHandle,
+    };
+
+    use super::*;
+
+    /// A stand-in for the website: records each request and answers with whatever `respond` says.
+    pub(crate) struct MockServer {
+        base_url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+        server: Arc<tiny_http::Server>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub(crate) struct Request {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    impl MockServer {
+        pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+            let server = Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+            let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+
+            let thread = {
+                let server = server.clone();
+                let requests = requests.clone();
+                std::thread::spawn(move || {
+                    for mut req in server.incoming_requests() {
+                        let mut body = String::new();
+                        req.as_reader().read_to_string(&mut body).unwrap();
+
+                        let cookie = req
+                            .headers()
+                            .iter()
+                            .find(|h| h.field.equiv("Cookie"))
+                            .map(|h| h.value.to_string());
+
+                        let request = Request {
+                            method: req.method().to_string(),
+                            url: req.url().to_string(),
+                            cookie,
+                            body,
+                        };
+
+                        let (status, text) = respond(&request);
+                        requests.lock().unwrap().push(request);
+
+                        let response =
+                            tiny_http::Response::from_string(text).with_status_code(status);
+                        req.respond(response).unwrap();
+                    }
+                })
+            };
+
+            Self {
+                base_url,
+                requests,
+                server,
+                thread: Some(thread),
+            }
+        }
+
+        pub fn config(&self) -> Config {
+            Config {
+                session: Some("cookie".to_string()),
+                base_url: Some(self.base_url.clone()),
+            }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl Drop for MockServer {
+        fn drop(&mut self) {
+            self.server.unblock();
+            if let Some(thread) = self.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+
+    /// A fresh, empty directory for one test.
+    pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn config_from_file_and_env() {
+        let dir = scratch_dir("config");
+        let path = dir.join("aoc.toml");
+        std::fs::write(&path, "session = \"from-file\"\n").unwrap();
+
+        let config = Config::load_from(&path, |_| None).unwrap();
+        assert_eq!(config.session.as_deref(), Some("from-file"));
+        assert_eq!(config.base_url, None);
+
+        let env = |var: &str| match var {
+            "AOC_SESSION" => Some("from-env".to_string()),
+            "AOC_BASE_URL" => Some("http://localhost:1234".to_string()),
+            _ => None,
+        };
+        let config = Config::load_from(&path, env).unwrap();
+        assert_eq!(config.session.as_deref(), Some("from-env"));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost:1234"));
+
+        let missing = Config::load_from(&dir.join("nope.toml"), |_| None).unwrap();
+        assert_eq!(missing, Config::default());
+        assert!(Client::new(&missing).is_err());
+
+        std::fs::write(&path, "sesion = \"typo\"\n").unwrap();
+        assert!(Config::load_from(&path, |_| None).is_err());
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let server = MockServer::start(|req| match req.url.as_str() {
+            "/2022/day/1/input" => (200, "1000\n2000\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let client = Client::new(&server.config()).unwrap();
+        let dir = scratch_dir("fetch");
+
+        let path = fetch(&client, 2022, 1, &dir).unwrap();
+        assert_eq!(path, dir.join("day01.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=cookie"));
+
+        // Already downloaded, so don't ask again.
+        let err = fetch(&client, 2022, 1, &dir).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{}", err);
+        assert_eq!(server.requests().len(), 1);
+
+        let err = fetch(&client, 2022, 2, &dir).unwrap_err();
+        assert!(
+            format!("{:#}", err).contains("isn't unlocked yet"),
+            "{:#}",
+            err
+        );
+        assert!(!dir.join("day02.txt").exists());
+    }
+
+    #[test]
+    fn empty_files_are_not_cached() {
+        let server = MockServer::start(|_| (200, "abc\n".to_string()));
+        let client = Client::new(&server.config()).unwrap();
+        let dir = scratch_dir("empty");
+
+        std::fs::write(dir.join("day05.txt"), "").unwrap();
+        fetch(&client, 2022, 5, &dir).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("day05.txt")).unwrap(),
+            "abc\n"
+        );
+    }
+}
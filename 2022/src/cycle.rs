@@ -0,0 +1,196 @@
+//! Finding where a long simulation starts repeating itself, so it doesn't have to run to the end.
+//!
+//! Simulations are described by a starting state and a `step` function. A `key` projects each state
+//! down to whatever determines its future (for example, the shape of the top of a tower instead of
+//! the whole tower), and two states with equal keys are treated as the same point in the cycle.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Steps `start..start + period` repeat forever.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// Brent's algorithm. Only keeps two states around, and calls `step` fewer times than Floyd's.
+///
+/// Never returns if the simulation doesn't cycle.
+pub fn brent<S: Clone, K: Eq>(
+    initial: &S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // Find the period: the hare runs ahead in powers of two until the tortoise catches it.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(initial);
+    let mut hare = step(initial);
+    let mut hare_key = key(&hare);
+
+    while tortoise != hare_key {
+        if power == period {
+            tortoise = hare_key;
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        hare_key = key(&hare);
+        period += 1;
+    }
+
+    // Find the start: with the hare one period ahead, they first meet where the cycle begins.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Floyd's tortoise and hare. Simpler than [`brent`], but does more stepping.
+///
+/// Never returns if the simulation doesn't cycle.
+pub fn floyd<S: Clone, K: Eq>(
+    initial: &S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    let mut tortoise = initial.clone();
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    let target = key(&tortoise);
+    let mut hare = step(&tortoise);
+    while key(&hare) != target {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// The `value` of the state after `n` steps, without running all `n` of them once the simulation
+/// starts repeating.
+///
+/// This only works if `value` changes by the same amount every time around the cycle (like the
+/// height of a tower, or a running total). Steps run in place, remembering every key seen so far, so
+/// this stops at the first repeat. If nothing repeats within `n` steps, that's just the whole
+/// simulation.
+pub fn extrapolate<S, K: Eq + Hash>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> i64,
+    n: usize,
+) -> i64 {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut values = vec![];
+
+    for i in 0..n {
+        let k = key(&state);
+        let v = value(&state);
+
+        if let Some(&start) = seen.get(&k) {
+            let period = i - start;
+            let laps = (n - start) / period;
+            let leftover = (n - start) % period;
+
+            let per_lap = v - values[start];
+            return values[start + leftover] + per_lap * laps as i64;
+        }
+
+        seen.insert(k, i);
+        values.push(v);
+        step(&mut state);
+    }
+
+    value(&state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 2, 3, ...
+    fn step(&x: &u32) -> u32 {
+        if x == 6 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn find_cycle() {
+        let expected = Cycle {
+            start: 2,
+            period: 5,
+        };
+        assert_eq!(brent(&0, step, |&x| x), expected);
+        assert_eq!(floyd(&0, step, |&x| x), expected);
+    }
+
+    #[test]
+    fn already_cycling() {
+        let expected = Cycle {
+            start: 0,
+            period: 5,
+        };
+        assert_eq!(brent(&2, step, |&x| x), expected);
+        assert_eq!(floyd(&2, step, |&x| x), expected);
+    }
+
+    #[test]
+    fn extrapolate_running_total() {
+        // Carry the running total along, but leave it out of the key.
+        let step = |(x, total): &mut (u32, i64)| {
+            *x = step(x);
+            *total += *x as i64;
+        };
+        let naive = |n| {
+            let mut state = (0, 0);
+            for _ in 0..n {
+                step(&mut state);
+            }
+            state.1
+        };
+
+        for n in [0, 1, 5, 7, 8, 100, 1234] {
+            assert_eq!(
+                extrapolate((0, 0), step, |s| s.0, |s| s.1, n),
+                naive(n),
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn extrapolate_without_a_cycle() {
+        // The key never repeats, so this has to run every step.
+        let count = |s: &i64| *s;
+        assert_eq!(extrapolate(0, |s| *s += 1, count, count, 1000), 1000);
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day01 {
+    elves: Vec<Elf>,
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let elves = parse(input)?;
+        Ok(Self { elves })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        part1(&self.elves)
+    }
+
+    fn part2(&self) -> anyhow::Result<u32> {
+        part2(self.elves.clone())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Elf {
+    fruits: Vec<u32>,
+}
+
+impl Elf {
+    fn total(&self) -> u32 {
+        self.fruits.iter().sum()
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Elf>> {
+    let mut elves: Vec<Elf> = vec![];
+
+    for section in input.split("\n\n") {
+        let mut elf = Elf::default();
+
+        for fruit in section.split('\n').filter(|line| !line.is_empty()) {
+            let cals: u32 = fruit.parse()?;
+            elf.fruits.push(cals);
+        }
+
+        elves.push(elf);
+    }
+
+    Ok(elves)
+}
+
+fn part1(elves: &[Elf]) -> anyhow::Result<u32> {
+    let Some(most_food) = elves.iter().max_by_key(|elf| elf.total()) else {
+        bail!("no elves");
+    };
+    Ok(most_food.total())
+}
+
+fn part2(mut elves: Vec<Elf>) -> anyhow::Result<u32> {
+    if elves.len() < 3 {
+        bail!("need at least three elves, found {}", elves.len());
+    }
+    elves[..].sort_by_key(|e| std::cmp::Reverse(e.total()));
+    Ok(elves[0..3].iter().map(Elf::total).sum())
+}
+
+aoc::example_tests! {
+    Day01,
+    part1 = "24000",
+    part2 = "45000",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_few_elves() {
+        let elves = parse("1000\n2000\n\n3000").unwrap();
+        assert_eq!(part1(&elves).unwrap(), 3000);
+        assert_eq!(
+            part2(elves).unwrap_err().to_string(),
+            "need at least three elves, found 2"
+        );
+    }
+}
@@ -1,10 +1,31 @@
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("../../input/day02.txt");
+use anyhow::bail;
+use aoc::{Input, Solution};
 
-fn main() {
-    println!("{:?}", part1(INPUT));
-    println!("{:?}", part2(INPUT));
+// The second column means something different in each part, so each part parses for itself.
+pub struct Day02 {
+    input: String,
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = input.text.clone();
+        Ok(Self { input })
+    }
+
+    fn part1(&self) -> anyhow::Result<u32> {
+        part1(&self.input)
+    }
+
+    fn part2(&self) -> anyhow::Result<u32> {
+        part2(&self.input)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -15,7 +36,7 @@ enum Play {
 }
 
 impl FromStr for Play {
-    type Err = std::convert::Infallible; // lol
+    type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -29,7 +50,7 @@ impl FromStr for Play {
             "Y" => Ok(Self::Paper),
             "Z" => Ok(Self::Scissors),
 
-            _ => panic!("unknown play: {}", s),
+            _ => bail!("unknown play: {:?}", s),
         }
     }
 }
@@ -71,19 +92,13 @@ impl Round {
     }
 }
 
-fn part1(input: &str) -> u32 {
-    let lines = input.split('\n').filter(|l| !l.is_empty());
-    let rounds: Vec<Round> = lines
-        .map(|l| {
-            let parts: Vec<&str> = l.split(' ').collect();
-            Round {
-                opponent: Play::from_str(parts[0]).unwrap(),
-                player: Play::from_str(parts[1]).unwrap(),
-            }
-        })
-        .collect();
-
-    rounds.iter().map(Round::score).sum()
+fn part1(input: &str) -> anyhow::Result<u32> {
+    let rounds: Vec<Round> = aoc::error::lines(input, |l| {
+        let (opponent, player) = aoc::scan!(l, "{} {}", Play, Play)?;
+        Ok(Round { opponent, player })
+    })?;
+
+    Ok(rounds.iter().map(Round::score).sum())
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -94,7 +109,7 @@ enum Outcome {
 }
 
 impl FromStr for Outcome {
-    type Err = std::convert::Infallible; // lol
+    type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -102,7 +117,7 @@ impl FromStr for Outcome {
             "Y" => Ok(Self::Tie),
             "Z" => Ok(Self::Win),
 
-            _ => panic!("unknown play: {}", s),
+            _ => bail!("unknown outcome: {:?}", s),
         }
     }
 }
@@ -126,20 +141,20 @@ impl Outcome {
     }
 }
 
-fn part2(input: &str) -> u32 {
-    let lines = input.split('\n').filter(|l| !l.is_empty());
-    let rounds: Vec<Round> = lines
-        .map(|l| {
-            let parts: Vec<&str> = l.split(' ').collect();
+fn part2(input: &str) -> anyhow::Result<u32> {
+    let rounds: Vec<Round> = aoc::error::lines(input, |l| {
+        let (opponent, outcome) = aoc::scan!(l, "{} {}", Play, Outcome)?;
 
-            let opponent = Play::from_str(parts[0]).unwrap();
-            let outcome = Outcome::from_str(parts[1]).unwrap();
+        let player = outcome.respond(opponent);
 
-            let player = outcome.respond(opponent);
+        Ok(Round { opponent, player })
+    })?;
 
-            Round { opponent, player }
-        })
-        .collect();
+    Ok(rounds.iter().map(Round::score).sum())
+}
 
-    rounds.iter().map(Round::score).sum()
+aoc::example_tests! {
+    Day02,
+    part1 = "15",
+    part2 = "12",
 }
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+use itertools::Itertools;
+
+pub struct Day03 {
+    rucksacks: Vec<String>,
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let rucksacks = input
+            .split('\n')
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect();
+        Ok(Self { rucksacks })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        part1(&self.rucksacks)
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        part2(&self.rucksacks)
+    }
+}
+
+fn part1(rucksacks: &[String]) -> anyhow::Result<usize> {
+    let mut sum = 0;
+    for line in rucksacks {
+        let mid = line.len() / 2;
+
+        let l = &line[..mid];
+        let r = &line[mid..];
+
+        let first: HashSet<char> = l.chars().collect();
+        let second: HashSet<char> = r.chars().collect();
+
+        let Some(shared) = first.intersection(&second).next() else {
+            bail!("nothing in both compartments of {:?}", line);
+        };
+        sum += priority(shared);
+    }
+    Ok(sum)
+}
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(c: &char) -> usize {
+    1 + ALPHABET.chars().position(|x| x == *c).unwrap_or_default()
+}
+
+fn part2(rucksacks: &[String]) -> anyhow::Result<usize> {
+    let mut sum = 0;
+    for (i, group) in (&rucksacks.iter().chunks(3)).into_iter().enumerate() {
+        let shared: HashSet<char> = group
+            .map(|rucksack| rucksack.chars().collect::<HashSet<char>>())
+            .reduce(|acc, item| acc.intersection(&item).cloned().collect())
+            .unwrap_or_default();
+
+        let Some(badge) = shared.iter().next() else {
+            bail!("group {} has no badge in common", i + 1);
+        };
+
+        sum += priority(badge);
+    }
+    Ok(sum)
+}
+
+aoc::example_tests! {
+    Day03,
+    part1 = "157",
+    part2 = "70",
+}
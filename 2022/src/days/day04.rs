@@ -0,0 +1,87 @@
+use aoc::{Input, Solution};
+
+pub struct Day04 {
+    pairs: Vec<(Assignment, Assignment)>,
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let pairs = parse_input(input)?;
+        Ok(Self { pairs })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(&self.pairs))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(part2(&self.pairs))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Assignment {
+    start: usize,
+    end: usize,
+}
+
+impl Assignment {
+    fn contains(&self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    fn overlaps(&self, other: Self) -> bool {
+        self.contains(other) || other.contains(*self)
+    }
+
+    fn intersect(&self, other: Self) -> Option<Self> {
+        use std::cmp::{max, min};
+        let start = max(self.start, other.start);
+        let end = min(self.end, other.end);
+
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    fn intersects(&self, other: Self) -> bool {
+        self.intersect(other).is_some()
+    }
+}
+
+fn part1(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs.iter().filter(|(l, r)| l.overlaps(*r)).count()
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<(Assignment, Assignment)>> {
+    aoc::error::lines(input, |line| {
+        let (l_start, l_end, r_start, r_end) =
+            aoc::scan!(line, "{}-{},{}-{}", usize, usize, usize, usize)?;
+        let l = Assignment {
+            start: l_start,
+            end: l_end,
+        };
+        let r = Assignment {
+            start: r_start,
+            end: r_end,
+        };
+        Ok((l, r))
+    })
+}
+
+fn part2(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs.iter().filter(|(l, r)| l.intersects(*r)).count()
+}
+
+aoc::example_tests! {
+    Day04,
+    part1 = "2",
+    part2 = "4",
+}
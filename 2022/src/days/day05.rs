@@ -0,0 +1,168 @@
+use anyhow::bail;
+use aoc::{Error, Input, Solution};
+
+pub struct Day05 {
+    ship: Ship,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let (ship, moves) = parse(input)?;
+        Ok(Self { ship, moves })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        part1(self.ship.clone(), &self.moves)
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        part2(self.ship.clone(), &self.moves)
+    }
+}
+
+type Crate = String;
+
+type Stack = Vec<Crate>;
+
+#[derive(Debug, Clone)]
+struct Ship {
+    stacks: Vec<Stack>,
+}
+
+impl Ship {
+    fn parse(input: &str) -> Self {
+        let lines: Vec<&str> = input.trim_end().split('\n').rev().collect();
+
+        let num_stacks = lines[0].split("   ").count();
+
+        let mut ship = Ship {
+            stacks: (0..num_stacks).map(|_| Vec::new()).collect(),
+        };
+
+        for line in &lines[1..] {
+            for stk in 0..num_stacks {
+                let i = 4 * stk + 1;
+                let contents = match line.get(i..i + 1) {
+                    None => continue,
+                    Some(" ") => continue,
+                    Some(contents) => contents,
+                };
+                ship.stacks[stk].push(contents.to_string());
+            }
+        }
+
+        ship
+    }
+
+    fn stack(&mut self, i: usize) -> anyhow::Result<&mut Stack> {
+        let len = self.stacks.len();
+        match self.stacks.get_mut(i) {
+            Some(stk) => Ok(stk),
+            None => bail!("no stack {} (there are only {})", i + 1, len),
+        }
+    }
+
+    /// Takes the top `count` crates off a stack, keeping them in order.
+    fn take(&mut self, m: Move) -> anyhow::Result<Vec<Crate>> {
+        let src = self.stack(m.from)?;
+        let Some(at) = src.len().checked_sub(m.count) else {
+            bail!(
+                "can't move {} crates from stack {}, it only has {}",
+                m.count,
+                m.from + 1,
+                src.len()
+            );
+        };
+        Ok(src.split_off(at))
+    }
+
+    fn do_move_1(&mut self, m: Move) -> anyhow::Result<()> {
+        let items = self.take(m)?;
+        self.stack(m.to)?.extend(items.into_iter().rev());
+        Ok(())
+    }
+
+    fn do_move_2(&mut self, m: Move) -> anyhow::Result<()> {
+        let items = self.take(m)?;
+        self.stack(m.to)?.extend(items);
+        Ok(())
+    }
+
+    fn tops(&self) -> anyhow::Result<String> {
+        let mut s = String::new();
+        for (i, stk) in self.stacks.iter().enumerate() {
+            match stk.last() {
+                Some(top) => s += top,
+                None => bail!("stack {} ended up empty", i + 1),
+            }
+        }
+        Ok(s)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let (count, from, to) = aoc::scan!(line, "move {} from {} to {}", usize, usize, usize)?;
+        if from == 0 || to == 0 {
+            bail!("stacks are numbered from 1");
+        }
+
+        Ok(Self {
+            count,
+            from: from - 1, // zero-index
+            to: to - 1,     // zero-index
+        })
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<(Ship, Vec<Move>)> {
+    let Some((ship, moves)) = input.split_once("\n\n") else {
+        bail!("expected the ship drawing, a blank line, and then the moves");
+    };
+
+    let ship = Ship::parse(ship);
+    let moves = aoc::error::lines(moves, Move::parse)
+        .map_err(|err| Error::from(err).after(ship_lines(input)))?;
+
+    Ok((ship, moves))
+}
+
+/// The drawing of the ship, plus the blank line after it.
+fn ship_lines(input: &str) -> usize {
+    input.lines().take_while(|l| !l.is_empty()).count() + 1
+}
+
+fn part1(mut ship: Ship, moves: &[Move]) -> anyhow::Result<String> {
+    for &m in moves {
+        ship.do_move_1(m)?;
+    }
+
+    ship.tops()
+}
+
+fn part2(mut ship: Ship, moves: &[Move]) -> anyhow::Result<String> {
+    for &m in moves {
+        ship.do_move_2(m)?;
+    }
+
+    ship.tops()
+}
+
+aoc::example_tests! {
+    Day05,
+    part1 = "CMZ",
+    part2 = "MCD",
+}
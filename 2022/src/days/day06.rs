@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day06 {
+    sig: Vec<char>,
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let sig: Vec<char> = input.trim().chars().collect();
+        Ok(Self { sig })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        find_marker(4, &self.sig)
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        find_marker(14, &self.sig)
+    }
+}
+
+fn all_different(c: &[char]) -> bool {
+    c.iter().cloned().collect::<HashSet<char>>().len() == c.len()
+}
+
+fn find_marker(width: usize, v: &[char]) -> anyhow::Result<usize> {
+    match v.windows(width).position(all_different) {
+        Some(i) => Ok(i + width),
+        None => bail!("no marker found"),
+    }
+}
+
+aoc::example_tests! {
+    Day06,
+    part1 = "7",
+    part2 = "19",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_marker() {
+        let sig: Vec<char> = "aaaa".chars().collect();
+        assert_eq!(
+            find_marker(4, &sig).unwrap_err().to_string(),
+            "no marker found"
+        );
+        assert_eq!(
+            find_marker(14, &sig).unwrap_err().to_string(),
+            "no marker found"
+        );
+    }
+}
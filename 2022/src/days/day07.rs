@@ -0,0 +1,175 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail};
+use aoc::{Error, Input, Solution};
+use path_absolutize::Absolutize;
+
+pub struct Day07 {
+    du: HashMap<PathBuf, Size>,
+}
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let du = explore(input)?;
+        Ok(Self { du })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(part1(&self.du))
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        part2(&self.du)
+    }
+}
+
+fn explore(input: &str) -> anyhow::Result<HashMap<PathBuf, Size>> {
+    let mut cwd = PathBuf::from("/"); // Because where else would we be?
+
+    let mut fs: HashMap<PathBuf, Size> = HashMap::new();
+
+    for block in parse(input)? {
+        let knowledge: Knowledge;
+        (cwd, knowledge) = match split_words(&block.cmd)[..] {
+            ["cd", dir] => cd(&cwd, dir).map_err(|err| block.error(err))?,
+            ["ls"] => ls(&cwd, &block)?,
+            _ => return Err(block.error(anyhow!("unknown command"))),
+        };
+
+        for (path, size) in &knowledge {
+            let Some(size) = size else { continue };
+            let old = fs.insert(path.to_path_buf(), *size);
+
+            if old.is_some_and(|old| old != *size) {
+                let err = anyhow!("{} changed size from {:?} to {}", path.display(), old, size);
+                return Err(block.error(err));
+            }
+        }
+    }
+
+    Ok(du(fs))
+}
+
+fn part1(du: &HashMap<PathBuf, Size>) -> u64 {
+    du.values().filter(|&&size| size <= 100_000).sum()
+}
+
+fn part2(du: &HashMap<PathBuf, Size>) -> anyhow::Result<u64> {
+    let total: Size = 70_000_000;
+    let needed: Size = 30_000_000;
+
+    let Some(&used) = du.get(Path::new("/")) else {
+        bail!("never found anything in /");
+    };
+    let Some(unused) = total.checked_sub(used) else {
+        bail!("{} used on a disk of {}", used, total);
+    };
+    let to_free = needed.saturating_sub(unused);
+
+    let smallest = du.values().copied().filter(|&size| size > to_free).min();
+
+    match smallest {
+        Some(size) => Ok(size),
+        None => bail!("no directory is big enough to free {}", to_free),
+    }
+}
+
+fn du(fs: HashMap<PathBuf, Size>) -> HashMap<PathBuf, Size> {
+    let mut dirs = HashMap::new();
+    for (path, size) in fs {
+        let mut dir = path;
+        while dir.pop() {
+            dirs.entry(dir.to_path_buf())
+                .and_modify(|s| *s += size)
+                .or_insert(size);
+        }
+    }
+    dirs
+}
+
+type Size = u64;
+type Knowledge = Vec<(PathBuf, Option<Size>)>;
+
+fn cd(cwd: &Path, arg: &str) -> anyhow::Result<(PathBuf, Knowledge)> {
+    let cwd = cwd.join(arg);
+    let cwd = cwd.absolutize()?;
+    Ok((cwd.to_path_buf(), Default::default()))
+}
+
+fn ls(cwd: &Path, block: &Block) -> anyhow::Result<(PathBuf, Knowledge)> {
+    let mut knowledge: Knowledge = vec![];
+
+    for (i, line) in block.out.iter().enumerate() {
+        let entry = match split_words(line)[..] {
+            ["dir", name] => Ok((cwd.join(name), None)),
+            [size, name] => size
+                .parse::<Size>()
+                .map(|size| (cwd.join(name), Some(size)))
+                .map_err(anyhow::Error::from),
+            _ => Err(anyhow!("expected a size (or \"dir\") and a name")),
+        };
+
+        match entry {
+            Ok(entry) => knowledge.push(entry),
+            Err(err) => return Err(Error::at_line(block.line + 1 + i, line, err).into()),
+        }
+    }
+    Ok((cwd.into(), knowledge))
+}
+
+/// A command, what it printed, and the line it started on.
+#[derive(Debug, Clone)]
+struct Block {
+    line: usize,
+    cmd: String,
+    out: Vec<String>,
+}
+
+impl Block {
+    fn error(&self, err: anyhow::Error) -> anyhow::Error {
+        Error::at_line(self.line, &format!("$ {}", self.cmd), err).into()
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Block>> {
+    let mut blocks: Vec<Block> = vec![];
+
+    for (i, line) in split_lines(input).into_iter().enumerate() {
+        if let Some(cmd) = line.strip_prefix("$ ") {
+            blocks.push(Block {
+                line: i + 1,
+                cmd: cmd.to_string(),
+                out: vec![],
+            });
+        } else if let Some(block) = blocks.last_mut() {
+            block.out.push(line.to_string());
+        } else {
+            let err = anyhow!("output before any command");
+            return Err(Error::at_line(i + 1, line, err).into());
+        }
+    }
+
+    Ok(blocks)
+}
+
+fn split_lines(s: &str) -> Vec<&str> {
+    s.trim_end().split('\n').collect()
+}
+
+fn split_words(s: &str) -> Vec<&str> {
+    s.split(' ').collect()
+}
+
+aoc::example_tests! {
+    Day07,
+    part1 = "95437",
+    part2 = "24933642",
+}
@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::bail;
+use aoc::{
+    grid::{Grid, ORTHOGONAL, RC},
+    Input, Solution,
+};
+
+pub struct Day08 {
+    forest: Forest,
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let forest = Forest::parse(input)?;
+        Ok(Self { forest })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        let visibility = self.forest.find_visible();
+        Ok(visibility.iter().filter(|(_, v)| !v.is_empty()).count())
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        let scenicality = self.forest.score_views();
+        match scenicality.values().max() {
+            Some(&best) => Ok(best),
+            None => bail!("no trees in the forest"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Visibility {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Visibility {
+    fn all() -> HashSet<Self> {
+        use Visibility::*;
+        HashSet::from([Top, Bottom, Left, Right])
+    }
+}
+
+struct Forest {
+    heights: Grid<u8>,
+}
+
+impl Forest {
+    fn parse(text: &str) -> anyhow::Result<Self> {
+        let heights = Grid::parse(text, |ch| match ch.to_digit(10) {
+            Some(height) => Ok(height as u8),
+            None => bail!("not a tree height: {:?}", ch),
+        })?;
+        Ok(Self { heights })
+    }
+
+    fn find_visible(&self) -> HashMap<RC, HashSet<Visibility>> {
+        // Assume all trees are visible from all sides until we learn that they're blocked.
+        let mut visibility: HashMap<RC, HashSet<Visibility>> = self
+            .heights
+            .positions()
+            .map(|rc| (rc, Visibility::all()))
+            .collect();
+
+        // Now see what each tree would block.
+        for (rc1, &height) in self.heights.iter() {
+            use Visibility::*;
+            let block = [
+                (Top, (-1, 0)),
+                (Bottom, (1, 0)),
+                (Right, (0, -1)),
+                (Left, (0, 1)),
+            ];
+
+            for (vis, delta) in block {
+                for rc in self.heights.ray(rc1, delta) {
+                    if height >= self.heights[rc] {
+                        visibility.entry(rc).and_modify(|v| {
+                            v.remove(&vis);
+                        });
+                    }
+                }
+            }
+        }
+
+        visibility
+    }
+
+    fn score_views(&self) -> HashMap<RC, usize> {
+        let mut scene: HashMap<RC, usize> = HashMap::new();
+
+        for (rc1, &height) in self.heights.iter() {
+            let blockage = |rc: &RC| -> bool { self.heights[*rc] >= height };
+
+            let score = ORTHOGONAL
+                .iter()
+                .map(|&delta| take_until(self.heights.ray(rc1, delta), blockage).len())
+                .product();
+
+            scene.insert(rc1, score);
+        }
+
+        scene
+    }
+}
+
+// This iterator is eager because _I'm_ lazy.
+fn take_until<P>(iter: impl Iterator<Item = RC>, mut predicate: P) -> Vec<RC>
+where
+    P: FnMut(&RC) -> bool,
+{
+    let mut v = vec![];
+    for i in iter {
+        v.push(i);
+
+        if predicate(&i) {
+            break;
+        }
+    }
+    v
+}
+
+aoc::example_tests! {
+    Day08,
+    part1 = "21",
+    part2 = "8",
+}
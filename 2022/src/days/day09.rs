@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use anyhow::bail;
+use aoc::{
+    point::{Heading, Point2},
+    Input, Solution,
+};
+
+pub struct Day09 {
+    steps: Vec<Step>,
+}
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let steps = parse_input(input)?;
+        Ok(Self { steps })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(simulate(&self.steps, 2))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(simulate(&self.steps, 10))
+    }
+}
+
+fn simulate(steps: &[Step], num_knots: usize) -> usize {
+    let mut knots = vec![Pos::default(); num_knots];
+    let tail = num_knots - 1;
+
+    let mut tail_visited: HashSet<Pos> = HashSet::new();
+    tail_visited.insert(knots[tail]);
+
+    for step in steps {
+        for _ in 0..(step.n) {
+            knots[0] += step.d.delta();
+            for i in 1..(knots.len()) {
+                knots[i] = follow(knots[i], knots[i - 1]);
+            }
+
+            tail_visited.insert(knots[tail]);
+        }
+    }
+
+    tail_visited.len()
+}
+
+type Pos = Point2<i64>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Step {
+    d: Heading,
+    n: i64,
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<Step>> {
+    aoc::error::lines(input, |line| {
+        let (d, n) = aoc::scan!(line, "{} {}", String, i64)?;
+
+        let d = match d.as_str() {
+            "U" => Heading::North,
+            "D" => Heading::South,
+            "L" => Heading::West,
+            "R" => Heading::East,
+            other => bail!("unknown direction: {:?}", other),
+        };
+
+        Ok(Step { d, n })
+    })
+}
+
+fn follow(knot: Pos, target: Pos) -> Pos {
+    // Adjacent
+    if knot.chebyshev(target) < 2 {
+        return knot;
+    }
+
+    knot + (target - knot).signum()
+}
+
+#[allow(dead_code)]
+fn render(knots: &[Pos], tail_visited: &HashSet<Pos>) {
+    let ps = knots.iter().chain(tail_visited.iter());
+    let (lo, hi) = ps.fold((Pos::default(), Pos::default()), |(lo, hi), p| {
+        (
+            Pos::new(lo.x.min(p.x), lo.y.min(p.y)),
+            Pos::new(hi.x.max(p.x), hi.y.max(p.y)),
+        )
+    });
+
+    let chr = move |p: Pos| -> char {
+        for (i, &knot) in knots.iter().enumerate() {
+            if p == knot {
+                return char::from_digit(i as u32, 10).unwrap();
+            }
+        }
+        if tail_visited.contains(&p) {
+            '#'
+        } else {
+            '.'
+        }
+    };
+
+    for y in lo.y..=hi.y {
+        for x in lo.x..=hi.x {
+            print!("{}", chr(Pos::new(x, y)));
+        }
+        println!();
+    }
+    println!();
+}
+
+aoc::example_tests! {
+    Day09,
+    part1 = "13",
+    part2 = "1",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_distance() {
+        let err = parse_input("R 4\nR").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected \" \" somewhere in \"R\"\n  | R\n  | ^"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day10 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let instructions = parse(input)?;
+        Ok(Self { instructions })
+    }
+
+    fn part1(&self) -> anyhow::Result<i64> {
+        Ok(self.crt().strength)
+    }
+
+    fn part2(&self) -> anyhow::Result<String> {
+        Ok(self.crt().image)
+    }
+}
+
+impl Day10 {
+    fn crt(&self) -> Crt {
+        let mut crt = Crt::new();
+
+        for &i in &self.instructions {
+            crt.run(i);
+        }
+
+        crt
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+    aoc::error::lines(input, |line| match line {
+        "noop" => Ok(Instruction::Noop),
+        _ if line.starts_with("addx ") => Ok(Instruction::Addx(aoc::scan!(line, "addx {}", i64)?)),
+        other => bail!("unknown instruction: {:?}", other),
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Crt {
+    cycle: usize,
+    x: i64,
+
+    strength: i64,
+    image: String,
+}
+
+impl Crt {
+    fn new() -> Self {
+        Self {
+            cycle: 0,
+            x: 1,
+
+            strength: 0,
+            image: String::new(),
+        }
+    }
+
+    fn run(&mut self, i: Instruction) {
+        let dxs = match i {
+            Instruction::Noop => vec![0],
+            Instruction::Addx(dx) => vec![0, dx],
+        };
+
+        for dx in dxs {
+            let scanx = (self.cycle as i64) % 40;
+            if scanx == 0 {
+                self.image += "\n";
+            }
+
+            self.cycle += 1;
+
+            let pixel = if (self.x - scanx).abs() < 2 { "#" } else { "." };
+            self.image += pixel;
+
+            let signal = (self.cycle as i64) * self.x;
+            if (self.cycle + 20).is_multiple_of(40) {
+                self.strength += signal;
+            }
+
+            self.x += dx;
+        }
+    }
+}
+
+aoc::example_tests! {
+    Day10,
+    part1 = "13140",
+    part2 = "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....",
+}
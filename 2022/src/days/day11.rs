@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let monkeys = aoc::error::blocks(input, Monkey::parse)?;
+
+        // Assumptions:
+        for (i, m) in monkeys.iter().enumerate() {
+            if i != m.id {
+                bail!("monkey {} is listed as monkey {}", i, m.id);
+            }
+            for target in [m.test.target_true, m.test.target_false] {
+                if target >= monkeys.len() || target == m.id {
+                    bail!("monkey {} can't throw to monkey {}", m.id, target);
+                }
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(monkey_business(self.monkeys.clone(), 20, 3))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(monkey_business(self.monkeys.clone(), 10_000, 1))
+    }
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize, confidence: i64) -> usize {
+    let yolo_factor: i64 = monkeys.iter().map(|m| m.test.divisor).product();
+
+    for _ in 1..=rounds {
+        for m in 0..monkeys.len() {
+            // Don't forget to put the monkey back in the vec when we're done!
+            //
+            // This is a workaround for simultaneous mutable references to multiple vec items.
+            let mut monkey = std::mem::take(&mut monkeys[m]);
+
+            for mut item in monkey.items.drain(0..) {
+                monkey.inspections += 1;
+                let operation = monkey
+                    .operation
+                    .expect("only a taken monkey has no operation");
+                item.worry = operation.apply(item.worry);
+
+                item.worry /= confidence;
+                item.worry %= yolo_factor;
+
+                let dest = monkey.test.apply(item.worry);
+                monkeys[dest].items.push_back(item);
+            }
+
+            monkeys[m] = monkey;
+        }
+    }
+
+    let mut counts: Vec<usize> = monkeys.iter().map(|m| m.inspections).collect();
+    counts.sort();
+    counts.iter().rev().take(2).product()
+}
+
+#[derive(Debug, Clone, Default)]
+struct Monkey {
+    id: usize,
+    items: VecDeque<Item>,
+    operation: Option<Operation>,
+    test: Test,
+
+    inspections: usize,
+}
+
+type Worry = i64;
+
+#[derive(Debug, Copy, Clone, Default)]
+struct Item {
+    worry: Worry,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Operation {
+    operator: Operator,
+    operand: Operand,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Operator {
+    Plus,
+    Times,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Operand {
+    Const(i64),
+    Old,
+}
+
+impl Operation {
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let (operator, operand) = aoc::scan!(line, "  Operation: new = old {} {}", char, String)?;
+
+        use {Operand::*, Operator::*};
+
+        let operator = match operator {
+            '+' => Plus,
+            '*' => Times,
+            other => bail!("unknown operator: {:?}", other),
+        };
+
+        let operand = match operand.as_str() {
+            "old" => Operand::Old,
+            i => Const(i.parse()?),
+        };
+
+        Ok(Operation { operator, operand })
+    }
+
+    fn apply(&self, old: Worry) -> Worry {
+        use {Operand::*, Operator::*};
+
+        match (self.operand, self.operator) {
+            (Const(n), Plus) => old + n,
+            (Const(n), Times) => old * n,
+            (Old, Plus) => old + old,
+            (Old, Times) => old * old,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+struct Test {
+    divisor: i64,
+    target_true: usize,
+    target_false: usize,
+}
+
+impl Test {
+    fn apply(&self, worry: Worry) -> usize {
+        if worry % self.divisor == 0 {
+            self.target_true
+        } else {
+            self.target_false
+        }
+    }
+}
+
+impl Monkey {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        use aoc::error::line;
+
+        let lines: Vec<&str> = aoc::lines(s).collect();
+
+        let id = line(&lines, 0, |l| Ok(aoc::scan!(l, "Monkey {}:", usize)?))?;
+
+        let items: VecDeque<Item> = line(&lines, 1, |l| {
+            aoc::scan!(l, "  Starting items: {}", String)?
+                .split(", ")
+                .map(|worry| {
+                    Ok(Item {
+                        worry: worry.parse()?,
+                    })
+                })
+                .collect()
+        })?;
+
+        let operation = Some(line(&lines, 2, Operation::parse)?);
+
+        let test = Test {
+            divisor: line(&lines, 3, |l| {
+                Ok(aoc::scan!(l, "  Test: divisible by {}", i64)?)
+            })?,
+            target_true: line(&lines, 4, |l| {
+                Ok(aoc::scan!(l, "    If true: throw to monkey {}", usize)?)
+            })?,
+            target_false: line(&lines, 5, |l| {
+                Ok(aoc::scan!(l, "    If false: throw to monkey {}", usize)?)
+            })?,
+        };
+
+        Ok(Self {
+            id,
+            items,
+            operation,
+            test,
+
+            inspections: 0,
+        })
+    }
+}
+
+aoc::example_tests! {
+    Day11,
+    part1 = "10605",
+    part2 = "2713310158",
+}
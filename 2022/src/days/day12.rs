@@ -0,0 +1,150 @@
+use std::io::Write;
+
+use anyhow::{bail, Context};
+use aoc::{
+    grid::{Grid, RC},
+    search, Input, Solution,
+};
+use termcolor::{BufferWriter, Color, ColorChoice, ColorSpec, WriteColor};
+
+pub struct Day12 {
+    hill: Hill,
+    start: RC,
+    goal: RC,
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let (hill, start, goal) = Hill::parse(input)?;
+        Ok(Self { hill, start, goal })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        part1(&self.hill, self.start, self.goal)
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        part2(&self.hill, self.goal)
+    }
+}
+
+fn part1(hill: &Hill, start: RC, goal: RC) -> anyhow::Result<usize> {
+    hill.bfs([start], goal).context("no way up from the start")
+}
+
+fn part2(hill: &Hill, goal: RC) -> anyhow::Result<usize> {
+    hill.bfs(hill.starts(), goal)
+        .context("no way up from any low point")
+}
+
+struct Hill {
+    map: Grid<u8>,
+}
+
+impl Hill {
+    fn parse(input: &str) -> anyhow::Result<(Self, RC, RC)> {
+        let letters = Grid::parse(input, |letter| match letter {
+            'S' | 'E' | 'a'..='z' => Ok(letter),
+            _ => bail!("not an elevation"),
+        })?;
+
+        let start = letters
+            .position(|&letter| letter == 'S')
+            .context("no start (S) on the map")?;
+        let goal = letters
+            .position(|&letter| letter == 'E')
+            .context("no goal (E) on the map")?;
+
+        let map = letters.map(|&letter| match letter {
+            'S' => 0,
+            'E' => b'z' - b'a',
+            letter => letter as u8 - b'a',
+        });
+        Ok((Self { map }, start, goal))
+    }
+
+    fn bfs(&self, starts: impl IntoIterator<Item = RC>, goal: RC) -> Option<usize> {
+        let path = search::bfs(starts, |&rc| self.neighbors(rc), |&rc| rc == goal)?;
+        Some(path.cost)
+    }
+
+    fn neighbors(&self, rc: RC) -> Vec<RC> {
+        let h = self.map[rc];
+        self.map
+            .neighbors4(rc)
+            .filter(|&neighbor| self.map[neighbor] <= h + 1)
+            .collect()
+    }
+
+    fn starts(&self) -> Vec<RC> {
+        self.map
+            .iter()
+            .filter(|&(_, &h)| h == 0)
+            .map(|(rc, _)| rc)
+            .collect()
+    }
+}
+
+#[allow(dead_code)]
+fn render(text: &str) -> anyhow::Result<()> {
+    let writer = BufferWriter::stderr(ColorChoice::Always);
+    let mut buf = writer.buffer();
+
+    for line in aoc::lines(text) {
+        for c in line.chars() {
+            buf.set_color(&color(c))?;
+            write!(&mut buf, "█")?;
+        }
+        writeln!(&mut buf)?;
+    }
+
+    buf.set_color(ColorSpec::new().set_reset(true))?;
+    writer.print(&buf)?;
+    Ok(())
+}
+
+fn color(c: char) -> ColorSpec {
+    let mut color = ColorSpec::new();
+    let spec = match c {
+        'S' => Color::Blue,
+        'E' => Color::Green,
+        c => {
+            let elev = (c as u64) - ('a' as u64);
+            let gray = (elev * 256 / 26) as u8;
+            Color::Rgb(gray, gray, gray)
+        }
+    };
+    color.set_fg(Some(spec));
+    color
+}
+
+#[allow(dead_code)]
+fn palette() -> anyhow::Result<()> {
+    let writer = BufferWriter::stderr(ColorChoice::Always);
+    let mut buf = writer.buffer();
+
+    let alphabet = "abcdefghijklmnopqrstuvwxyz";
+
+    writeln!(&mut buf, "{}", alphabet)?;
+
+    for c in alphabet.chars() {
+        buf.set_color(&color(c))?;
+        write!(&mut buf, "█")?;
+    }
+    writeln!(&mut buf)?;
+
+    buf.set_color(ColorSpec::new().set_reset(true))?;
+    writer.print(&buf)?;
+    Ok(())
+}
+
+aoc::example_tests! {
+    Day12,
+    part1 = "31",
+    part2 = "29",
+}
@@ -0,0 +1,289 @@
+use std::{cmp::Ordering, fmt::Display, iter::Peekable, str::CharIndices};
+
+use aoc::{scan::Mismatch, Input, Solution};
+
+pub struct Day13 {
+    packets: Vec<Packet>,
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let packets = parse(input)?;
+        Ok(Self { packets })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(&self.packets))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(part2(&self.packets))
+    }
+}
+
+fn part1(packets: &[Packet]) -> usize {
+    packets
+        .iter()
+        .enumerate()
+        .filter_map(|(i, packet)| {
+            if packet.left < packet.right {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part2(packets: &[Packet]) -> usize {
+    let p2 = Data::divider(2);
+    let p6 = Data::divider(6);
+
+    let mut packets: Vec<Data> = packets
+        .iter()
+        .flat_map(|p| vec![p.left.clone(), p.right.clone()])
+        .collect();
+
+    packets.sort();
+
+    let mut i2 = 1; // 1-indexing
+    let mut i6 = 2; // 1-indexing + p2 placeholder
+
+    for p in packets {
+        if p < p2 {
+            i2 += 1;
+        }
+        if p < p6 {
+            i6 += 1;
+        }
+    }
+
+    i2 * i6
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Packet>> {
+    aoc::error::blocks(input, |block| {
+        let lines: Vec<&str> = aoc::lines(block).collect();
+        let left = aoc::error::line(&lines, 0, |l| Ok(Data::parse(l)?))?;
+        let right = aoc::error::line(&lines, 1, |l| Ok(Data::parse(l)?))?;
+
+        Ok(Packet { left, right })
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Packet {
+    left: Data,
+    right: Data,
+}
+
+#[derive(Debug, Clone)]
+enum Data {
+    Value(u32),
+    List(Vec<Data>),
+}
+
+type Chars<'a> = Peekable<CharIndices<'a>>;
+
+impl Data {
+    fn parse(line: &str) -> Result<Self, Mismatch> {
+        let mut chars = line.char_indices().peekable();
+        let list = Data::parse_list(&mut chars)?;
+        match chars.next() {
+            None => Ok(list),
+            Some((i, c)) => Err(Data::mismatch(
+                i,
+                format!("unexpected {:?} after the end", c),
+            )),
+        }
+    }
+
+    fn mismatch(i: usize, message: String) -> Mismatch {
+        Mismatch {
+            column: i + 1,
+            message,
+        }
+    }
+
+    fn parse_num(chars: &mut Chars) -> Result<Self, Mismatch> {
+        let mut digits = String::new();
+        let mut start = None;
+        while let Some(&(i, c)) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            start.get_or_insert(i);
+            digits.push(c);
+            chars.next();
+        }
+
+        match (start, digits.parse()) {
+            (Some(_), Ok(v)) => Ok(Data::Value(v)),
+            (Some(i), Err(err)) => Err(Data::mismatch(i, format!("bad number: {}", err))),
+            (None, _) => {
+                let (i, c) = chars.peek().copied().unwrap_or_default();
+                Err(Data::mismatch(
+                    i,
+                    format!("expected a number, found {:?}", c),
+                ))
+            }
+        }
+    }
+
+    fn parse_list(chars: &mut Chars) -> Result<Self, Mismatch> {
+        match chars.next() {
+            Some((_, '[')) => {}
+            Some((i, c)) => return Err(Data::mismatch(i, format!("expected '[', found {:?}", c))),
+            None => return Err(Data::mismatch(0, "expected a list".to_string())),
+        }
+
+        let mut list = vec![];
+        loop {
+            let val = match chars.peek() {
+                Some((_, ']')) => {
+                    chars.next();
+                    break;
+                }
+                Some((_, '[')) => Self::parse_list(chars)?,
+                Some((_, ',')) => {
+                    chars.next();
+                    continue;
+                }
+                Some(_) => Self::parse_num(chars)?,
+                None => return Err(Data::mismatch(0, "list never ends".to_string())),
+            };
+            list.push(val);
+        }
+
+        Ok(Data::List(list))
+    }
+
+    /// One of the extra packets from part 2: `[[n]]`.
+    fn divider(n: u32) -> Self {
+        Data::List(vec![Data::List(vec![Data::Value(n)])])
+    }
+}
+
+impl PartialEq for Data {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Data {}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Data::Value(l), Data::Value(r)) => l.cmp(r),
+            (Data::List(l), Data::List(r)) => Data::zip_cmp(l, r),
+
+            (Data::Value(_), Data::List(_)) => self.to_list().cmp(other),
+            (Data::List(_), Data::Value(_)) => self.cmp(&other.to_list()),
+        }
+    }
+}
+
+impl Data {
+    fn to_list(&self) -> Self {
+        match self {
+            Data::List(_) => self.clone(),
+            Data::Value(_) => Data::List(vec![self.clone()]),
+        }
+    }
+
+    fn zip_cmp(l: &[Data], r: &[Data]) -> Ordering {
+        let mut ll = l.iter();
+        let mut rr = r.iter();
+
+        loop {
+            match (ll.next(), rr.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(lll), Some(rrr)) => {
+                    let d = lll.cmp(rrr);
+                    if d != Ordering::Equal {
+                        return d;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Display for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Value(v) => write!(f, "{}", v),
+            Data::List(l) => {
+                let items = l
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",");
+
+                write!(f, "[{}]", items)
+            }
+        }
+    }
+}
+
+aoc::example_tests! {
+    Day13,
+    part1 = "13",
+    part2 = "140",
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        let line = "[[2],9]";
+        let data = Data::parse(line).unwrap();
+
+        assert_eq!(data.to_string(), line);
+
+        use Data::{List, Value};
+        assert_eq!(data, List(vec![List(vec![Value(2)]), Value(9)]))
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Data::parse("[1,[2,x]]").unwrap_err();
+        assert_eq!(err.to_string(), "column 7: expected a number, found 'x'");
+
+        let err = Data::parse("[1]]").unwrap_err();
+        assert_eq!(err.to_string(), "column 4: unexpected ']' after the end");
+    }
+
+    #[test]
+    fn test_parser() {
+        let input = aoc::input::Source::Real
+            .read(Path::new(aoc::input::INPUT_DIR), 13)
+            .unwrap();
+        let out = parse(&input)
+            .unwrap()
+            .iter()
+            .map(|p| format!("{}\n{}", p.left, p.right))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+            + "\n";
+        assert_eq!(out, input.text);
+    }
+}
@@ -1,27 +1,41 @@
-use std::collections::HashSet;
+use anyhow::bail;
+use aoc::{grid::RC, BitGrid, Input, Solution};
 
-const INPUT: &str = include_str!("../../input/day14.txt");
+pub struct Day14 {
+    wall: Wall,
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
 
-fn main() {
-    let paths = aoc::lines(INPUT).map(Path::parse).collect();
-    let wall = Wall::build(paths);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let paths = aoc::error::lines(input, Path::parse)?;
+        let wall = Wall::build(paths);
+        Ok(Self { wall })
+    }
 
-    println!("{}", part1(wall.clone()));
-    println!("{}", part2(wall));
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(self.wall.clone()))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(part2(self.wall.clone()))
+    }
 }
 
 fn part1(mut wall: Wall) -> usize {
     while wall.add_sand_1().is_some() {}
 
-    wall.render();
-    wall.sand.len()
+    wall.sand_count()
 }
 
 fn part2(mut wall: Wall) -> usize {
     while let Some(_pos) = wall.add_sand_2() {}
 
-    wall.render();
-    wall.sand.len()
+    wall.sand_count()
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -46,18 +60,25 @@ impl std::fmt::Display for Point {
 struct Path(Vec<Point>);
 
 impl Path {
-    fn parse(line: &str) -> Self {
-        let waypoints = line
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let waypoints: Vec<Point> = line
             .split(" -> ")
             .map(|point| {
-                let parts: Vec<&str> = point.split(',').collect();
-                let x: i32 = parts[0].parse().unwrap();
-                let y: i32 = parts[1].parse().unwrap();
-                Point { x, y }
+                let (x, y) = aoc::scan!(point, "{},{}", i32, i32)?;
+                if y < 0 {
+                    bail!("{} is above the sand source", point);
+                }
+                Ok(Point { x, y })
             })
-            .collect();
+            .collect::<anyhow::Result<_>>()?;
 
-        Self(waypoints)
+        let path = Self(waypoints);
+        for Segment { from, to } in path.segments() {
+            if from.x != to.x && from.y != to.y {
+                bail!("{} is off-axis from {}", to, from);
+            }
+        }
+        Ok(path)
     }
 
     fn segments(&self) -> Vec<Segment> {
@@ -88,15 +109,21 @@ impl Segment {
             let (x_min, x_max) = minmax(self.from.x, self.to.x);
             (x_min..=x_max).map(|x| Point { x, y }).collect()
         } else {
-            panic!("{:?} off-axis from {:?}", self.from, self.to)
+            unreachable!(
+                "{} off-axis from {} (Path::parse checks for this)",
+                self.to, self.from
+            )
         }
     }
 }
 
+/// Everything that could ever fill up fits in the triangle under the sand source, so the wall only
+/// needs to track that much.
 #[derive(Debug, Clone)]
 struct Wall {
-    rocks: HashSet<Point>,
-    sand: HashSet<Point>,
+    rocks: BitGrid,
+    sand: BitGrid,
+    x_min: i32,
     y_abyss: i32,
     y_floor: i32,
 }
@@ -105,47 +132,83 @@ impl Wall {
     const SAND_SOURCE: Point = Point { x: 500, y: 0 };
 
     fn build(paths: Vec<Path>) -> Self {
-        let sand = HashSet::new();
-        let mut rocks = HashSet::new();
-        let mut y_abyss = 0i32;
-
-        for path in paths {
-            for segment in path.segments() {
-                for point in segment.tween() {
-                    rocks.insert(point);
-                    y_abyss = y_abyss.max(point.y);
-                }
-            }
-        }
+        let points: Vec<Point> = paths
+            .iter()
+            .flat_map(|path| path.segments())
+            .flat_map(|segment| segment.tween())
+            .collect();
 
+        let y_abyss = points.iter().map(|p| p.y).max().unwrap_or(0);
         let y_floor = y_abyss + 2;
 
-        Self {
-            rocks,
-            sand,
+        // Sand can pile up as far sideways as it falls, plus one for the edges of the pile.
+        let spread = y_floor + 1;
+        let x_min = points
+            .iter()
+            .map(|p| p.x)
+            .chain([Wall::SAND_SOURCE.x - spread])
+            .min()
+            .unwrap();
+        let x_max = points
+            .iter()
+            .map(|p| p.x)
+            .chain([Wall::SAND_SOURCE.x + spread])
+            .max()
+            .unwrap();
+
+        let height = (y_floor + 1) as usize;
+        let width = (x_max - x_min + 1) as usize;
+
+        let mut wall = Self {
+            rocks: BitGrid::new(height, width),
+            sand: BitGrid::new(height, width),
+            x_min,
             y_abyss,
             y_floor,
+        };
+        for point in points {
+            let rc = wall.cell(&point).unwrap();
+            wall.rocks.insert(rc);
         }
+        wall
+    }
+
+    /// Where the point is in the grids, if it's anywhere.
+    fn cell(&self, p: &Point) -> Option<RC> {
+        let r = usize::try_from(p.y).ok()?;
+        let c = usize::try_from(p.x - self.x_min).ok()?;
+        self.rocks.in_bounds((r, c)).then_some((r, c))
+    }
+
+    fn point(&self, (r, c): RC) -> Point {
+        Point::new(c as i32 + self.x_min, r as i32)
     }
 
+    fn sand_count(&self) -> usize {
+        self.sand.len()
+    }
+
+    #[allow(dead_code)]
     fn render(&self) {
         let (lo, hi) = self.rocks.iter().chain(self.sand.iter()).fold(
             (Wall::SAND_SOURCE, Wall::SAND_SOURCE),
-            |(lo, hi), Point { x, y }| {
+            |(lo, hi), rc| {
+                let Point { x, y } = self.point(rc);
                 (
-                    Point::new(lo.x.min(*x), lo.y.min(*y)),
-                    Point::new(hi.x.max(*x), hi.y.max(*y)),
+                    Point::new(lo.x.min(x), lo.y.min(y)),
+                    Point::new(hi.x.max(x), hi.y.max(y)),
                 )
             },
         );
 
         let chr = move |x: i32, y: i32| -> char {
             let p = Point { x, y };
+            let rc = self.cell(&p);
             if p == Wall::SAND_SOURCE {
                 '+'
-            } else if self.rocks.contains(&p) {
+            } else if rc.is_some_and(|rc| self.rocks.get(rc)) {
                 '#'
-            } else if self.sand.contains(&p) {
+            } else if rc.is_some_and(|rc| self.sand.get(rc)) {
                 'o'
             } else {
                 '.'
@@ -176,10 +239,13 @@ impl Point {
 
 impl Wall {
     fn filled(&self, p: &Point) -> bool {
-        p == &Wall::SAND_SOURCE
-            || self.rocks.contains(p)
-            || self.sand.contains(p)
-            || p.y >= self.y_floor
+        if p == &Wall::SAND_SOURCE || p.y >= self.y_floor {
+            return true;
+        }
+        match self.cell(p) {
+            Some(rc) => self.rocks.get(rc) || self.sand.get(rc),
+            None => false,
+        }
     }
 
     fn empty(&self, p: &Point) -> bool {
@@ -190,7 +256,7 @@ impl Wall {
         let pos = self.drop_sand(self.y_abyss);
 
         if pos.y < self.y_abyss {
-            self.sand.insert(pos);
+            self.sand.insert(self.cell(&pos)?);
             Some(pos)
         } else {
             None
@@ -200,7 +266,7 @@ impl Wall {
     fn add_sand_2(&mut self) -> Option<Point> {
         let pos = self.drop_sand(self.y_floor);
 
-        if self.sand.insert(pos) {
+        if self.sand.insert(self.cell(&pos)?) {
             Some(pos)
         } else {
             None
@@ -239,3 +305,9 @@ fn minmax<T: PartialOrd>(a: T, b: T) -> (T, T) {
         (b, a)
     }
 }
+
+aoc::example_tests! {
+    Day14,
+    part1 = "24",
+    part2 = "93",
+}
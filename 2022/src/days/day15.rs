@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+
+use anyhow::bail;
+use aoc::{Input, IntervalSet, Solution};
+
+pub struct Day15 {
+    pairs: Vec<(Sensor, Beacon)>,
+    y: i32,
+    max: i32,
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let pairs = aoc::error::lines(input, parse_line)?;
+        let (y, max) = if input.is_example() {
+            (10, 20)
+        } else {
+            (2_000_000, 4_000_000)
+        };
+        Ok(Self { pairs, y, max })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(&self.pairs, self.y))
+    }
+
+    fn part2(&self) -> anyhow::Result<i64> {
+        part2(&self.pairs, self.max)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl std::fmt::Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Pos {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    fn distance(&self, other: Pos) -> i32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        dx.abs() + dy.abs()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Sensor {
+    pos: Pos,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Beacon {
+    pos: Pos,
+}
+
+/// Which x positions on row `yy` are covered by at least one sensor.
+fn coverage(pairs: &[(Sensor, Beacon)], yy: i32) -> IntervalSet<i32> {
+    let mut coverage = IntervalSet::new();
+
+    for (s, b) in pairs {
+        let d_cover = s.pos.distance(b.pos);
+        let dy = s.pos.distance(Pos::new(s.pos.x, yy));
+        let width = d_cover - dy;
+
+        let x = s.pos.x;
+        coverage.insert((x - width)..=(x + width));
+    }
+
+    coverage
+}
+
+fn part1(pairs: &[(Sensor, Beacon)], yy: i32) -> usize {
+    let coverage = coverage(pairs, yy);
+
+    let beacons: HashSet<Pos> = pairs
+        .iter()
+        .map(|(_, b)| b.pos)
+        .filter(|pos| pos.y == yy && coverage.contains(pos.x))
+        .collect();
+
+    coverage.len() as usize - beacons.len()
+}
+
+fn parse_line(line: &str) -> anyhow::Result<(Sensor, Beacon)> {
+    let (sx, sy, bx, by) = aoc::scan!(
+        line,
+        "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        i32,
+        i32,
+        i32,
+        i32,
+    )?;
+
+    Ok((
+        Sensor {
+            pos: Pos { x: sx, y: sy },
+        },
+        Beacon {
+            pos: Pos { x: bx, y: by },
+        },
+    ))
+}
+
+fn part2(pairs: &[(Sensor, Beacon)], max: i32) -> anyhow::Result<i64> {
+    for yy in 0..=max {
+        if let Some(gap) = coverage(pairs, yy).gaps(0..=max).next() {
+            let xx = *gap.start();
+            return Ok(4_000_000 * (xx as i64) + (yy as i64));
+        }
+    }
+
+    bail!("no gap for the distress beacon anywhere up to {}", max)
+}
+
+aoc::example_tests! {
+    Day15,
+    part1 = "26",
+    part2 = "56000011",
+}
@@ -0,0 +1,423 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::bail;
+use aoc::{graph::Graph, Input, Solution};
+
+pub struct Day16 {
+    volcano: Volcano,
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Answer1 = Flow;
+    type Answer2 = Flow;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let volcano = Volcano::parse(input)?;
+        Ok(Self { volcano })
+    }
+
+    fn part1(&self) -> anyhow::Result<Flow> {
+        Ok(self.volcano.max_flow(30)?.flow)
+    }
+
+    fn part2(&self) -> anyhow::Result<Flow> {
+        self.volcano.max_flow_together(26, 2)
+    }
+}
+
+// I really want this to be Copy, so here's a hack to avoid strings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Valve(u64);
+
+impl Valve {
+    /// Where we start out.
+    const START: Self = Self::new(*b"AA");
+
+    const fn new([a, b]: [u8; 2]) -> Self {
+        Self(((a as u64) << 8) | b as u64)
+    }
+}
+
+impl FromStr for Valve {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[a, b] if a.is_ascii_uppercase() && b.is_ascii_uppercase() => Ok(Self::new([a, b])),
+            _ => bail!("valve names are two capital letters, not {:?}", s),
+        }
+    }
+}
+
+impl std::fmt::Display for Valve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let u = self.0;
+        write!(f, "{}{}", (u >> 8) as u8 as char, (u & 0xff) as u8 as char)
+    }
+}
+
+type Flow = u32;
+type Pressure = u32;
+
+#[derive(Debug, Clone)]
+struct Volcano {
+    valves: HashMap<Valve, Pressure>,
+    tunnels: Graph<Valve, u32>,
+}
+
+impl Volcano {
+    fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut valves = HashMap::new();
+        let mut tunnels: Graph<Valve, u32> = Graph::new();
+
+        let lines = aoc::error::lines(text, |line| {
+            // Grammar!
+            let (valve, flow_rate, neighbors) = aoc::scan!(
+                line,
+                "Valve {} has flow rate={}; tunnels lead to valves {}",
+                Valve,
+                Pressure,
+                String,
+            )
+            .or_else(|_| {
+                aoc::scan!(
+                    line,
+                    "Valve {} has flow rate={}; tunnel leads to valve {}",
+                    Valve,
+                    Pressure,
+                    String,
+                )
+            })?;
+
+            let neighbors: Vec<Valve> = neighbors
+                .split(", ")
+                .map(str::parse)
+                .collect::<anyhow::Result<_>>()?;
+            Ok((valve, flow_rate, neighbors))
+        })?;
+
+        for (valve, flow_rate, neighbors) in lines {
+            valves.insert(valve, flow_rate);
+            for n in neighbors {
+                tunnels.add_edge(valve, n, 1);
+            }
+        }
+
+        if !valves.contains_key(&Valve::START) {
+            bail!("there's no valve {} to start at", Valve::START);
+        }
+        if let Some(v) = tunnels.nodes().iter().find(|v| !valves.contains_key(v)) {
+            bail!("tunnels lead to valve {}, but it's not in the scan", v);
+        }
+
+        Ok(Self { valves, tunnels })
+    }
+}
+
+impl Volcano {
+    /// The most pressure one agent can release, and how.
+    fn max_flow(&self, total_minutes: usize) -> anyhow::Result<Route> {
+        let network = self.network()?;
+        let mut memo = HashMap::new();
+
+        let mut state = (network.start(), 0, total_minutes as u32);
+        let flow = network.best_from(state, &mut memo);
+
+        // Retrace the search: at every step, some valve must account for the rest of the best flow.
+        let mut opened = vec![];
+        let mut rest = flow;
+        while rest > 0 {
+            let (next, gained) = network
+                .moves(state)
+                .find(|&(next, gained)| gained + network.best_from(next, &mut memo) == rest)
+                .expect("the best route goes somewhere");
+
+            let (valve, _, minutes_left) = next;
+            opened.push((network.valves[valve], total_minutes as u32 - minutes_left));
+            rest -= gained;
+            state = next;
+        }
+
+        Ok(Route { flow, opened })
+    }
+
+    /// The most pressure `agents` can release together, each walking around and opening valves on
+    /// their own. Nobody gains anything by opening a valve someone else already has, so this is
+    /// the best way to split the valves up between them.
+    fn max_flow_together(&self, total_minutes: usize, agents: usize) -> anyhow::Result<Flow> {
+        let network = self.network()?;
+        if network.valves.len() > Network::MAX_VALVES {
+            bail!(
+                "{} valves have some flow, but splitting up more than {} takes too long",
+                network.valves.len(),
+                Network::MAX_VALVES
+            );
+        }
+
+        let within = network.best_within(total_minutes);
+        let all = within.len() - 1;
+
+        if agents == 0 {
+            return Ok(0);
+        }
+
+        // What the first n agents can do with each set of valves, one more agent at a time.
+        let mut team = within.clone();
+        for _ in 2..agents {
+            team = (0..=all)
+                .map(|mask| {
+                    submasks(mask)
+                        .map(|mine| within[mine] + team[mask ^ mine])
+                        .max()
+                        .unwrap()
+                })
+                .collect();
+        }
+
+        if agents == 1 {
+            return Ok(team[all]);
+        }
+        Ok(submasks(all)
+            .map(|mine| within[mine] + team[all ^ mine])
+            .max()
+            .unwrap())
+    }
+
+    fn network(&self) -> anyhow::Result<Network> {
+        let mut valves: Vec<Valve> = self
+            .valves
+            .iter()
+            .filter(|&(_, &pressure)| pressure > 0)
+            .map(|(&v, _)| v)
+            .collect();
+        valves.sort();
+
+        let pressures = valves.iter().map(|v| self.valves[v]).collect();
+
+        let tunnels = &self.tunnels;
+        let paths = tunnels.shortest_paths();
+        let dist = valves
+            .iter()
+            .chain([&Valve::START])
+            .map(|v| tunnels.index(v))
+            .map(|i| {
+                valves
+                    .iter()
+                    .map(|v| {
+                        let j = tunnels.index(v)?;
+                        paths.dist(i?, j)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Network {
+            valves,
+            pressures,
+            dist,
+        })
+    }
+}
+
+/// The valves worth opening, numbered so sets of them fit in a bitmask.
+struct Network {
+    valves: Vec<Valve>,
+    pressures: Vec<Pressure>,
+    /// Minutes to walk from valve `i` (or the start, at the end) to valve `j`, if it's reachable.
+    dist: Vec<Vec<Option<u32>>>,
+}
+
+impl Network {
+    /// Splitting valves between agents gives every set of valves a slot in a table (2^n of them),
+    /// and every agent past the second has to look at every way of splitting each set in two (3^n). Real scans have 15 valves worth
+    /// opening, so this leaves a little room without letting a big scan run for hours.
+    const MAX_VALVES: usize = 16;
+
+    fn start(&self) -> usize {
+        self.valves.len()
+    }
+
+    /// Where an agent can go next to open another valve, and how much pressure that releases
+    /// over the time that's left.
+    fn moves(
+        &self,
+        (here, opened, minutes_left): State,
+    ) -> impl Iterator<Item = (State, Flow)> + '_ {
+        self.pressures
+            .iter()
+            .enumerate()
+            .filter(move |&(next, _)| opened & (1 << next) == 0)
+            .filter_map(move |(next, pressure)| {
+                let distance = self.dist[here][next]?;
+                // Walk over, then spend a minute opening it.
+                let minutes_left = minutes_left.checked_sub(distance + 1)?;
+                let state = (next, opened | (1 << next), minutes_left);
+                Some((state, pressure * minutes_left))
+            })
+    }
+
+    /// The most pressure one agent can still release from here.
+    fn best_from(&self, state: State, memo: &mut HashMap<State, Flow>) -> Flow {
+        if let Some(&flow) = memo.get(&state) {
+            return flow;
+        }
+        let best = self
+            .moves(state)
+            .map(|(next, gained)| gained + self.best_from(next, memo))
+            .max()
+            .unwrap_or(0);
+        memo.insert(state, best);
+        best
+    }
+
+    /// The most pressure one agent can release by opening exactly each set of valves (or 0 if
+    /// there isn't time to open them all).
+    fn best_exactly(&self, total_minutes: usize) -> Vec<Flow> {
+        let mut best = vec![0; 1 << self.valves.len()];
+        let mut memo = HashMap::new();
+        let start = (self.start(), 0, total_minutes as u32);
+        self.visit(start, 0, &mut memo, &mut best);
+        best
+    }
+
+    /// Like [`Network::best_from`], but forwards: `memo` has the most pressure released on the way
+    /// to each state, so there's no need to go on from a state that's been reached with at least
+    /// as much before.
+    fn visit(&self, state: State, flow: Flow, memo: &mut HashMap<State, Flow>, best: &mut [Flow]) {
+        if memo.get(&state).is_some_and(|&seen| seen >= flow) {
+            return;
+        }
+        memo.insert(state, flow);
+
+        let (_, opened, _) = state;
+        best[opened] = best[opened].max(flow);
+
+        for (next, gained) in self.moves(state) {
+            self.visit(next, flow + gained, memo, best);
+        }
+    }
+
+    /// The most pressure one agent can release by opening any of each set of valves.
+    fn best_within(&self, total_minutes: usize) -> Vec<Flow> {
+        let mut best = self.best_exactly(total_minutes);
+        for i in 0..self.valves.len() {
+            for mask in 0..best.len() {
+                if mask & (1 << i) != 0 {
+                    best[mask] = best[mask].max(best[mask ^ (1 << i)]);
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Where an agent is (by index in the network), which valves are open, and how many minutes are
+/// left.
+type State = (usize, usize, u32);
+
+/// The valves one agent opens, in order, with how many minutes in each one finished opening.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Route {
+    flow: Flow,
+    opened: Vec<(Valve, u32)>,
+}
+
+impl std::fmt::Display for Route {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Valve::START)?;
+        for (valve, minute) in &self.opened {
+            write!(f, " -> {} ({})", valve, minute)?;
+        }
+        write!(f, ": {}", self.flow)
+    }
+}
+
+/// Every subset of the mask, including the empty one.
+fn submasks(mask: usize) -> impl Iterator<Item = usize> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let sub = next?;
+        next = (sub != 0).then(|| (sub - 1) & mask);
+        Some(sub)
+    })
+}
+
+aoc::example_tests! {
+    Day16,
+    part1 = "1651",
+    part2 = "1707",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+    fn example() -> Volcano {
+        Volcano::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn teams() {
+        let volcano = example();
+        assert_eq!(volcano.max_flow_together(30, 1).unwrap(), 1651);
+        assert_eq!(volcano.max_flow_together(26, 2).unwrap(), 1707);
+        assert_eq!(volcano.max_flow_together(26, 3).unwrap(), 1794);
+        assert_eq!(volcano.max_flow_together(26, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn route() {
+        let route = example().max_flow(30).unwrap();
+        assert_eq!(
+            route.to_string(),
+            "AA -> DD (2) -> BB (5) -> JJ (9) -> HH (17) -> EE (21) -> CC (24): 1651"
+        );
+    }
+
+    #[test]
+    fn too_many_valves() {
+        // AA -> AB -> ... -> AR -> AA in a loop, all of them with some flow except AA.
+        let names: Vec<String> = (b'A'..=b'R').map(|c| format!("A{}", c as char)).collect();
+        let scan: String = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let next = &names[(i + 1) % names.len()];
+                let rate = u32::from(i > 0);
+                format!("Valve {name} has flow rate={rate}; tunnel leads to valve {next}\n")
+            })
+            .collect();
+
+        // One agent doesn't split the valves up, so it isn't limited. It takes two minutes to walk
+        // to and open each valve: 28 + 26 + ... + 2.
+        let volcano = Volcano::parse(&scan).unwrap();
+        assert_eq!(volcano.max_flow(30).unwrap().flow, 210);
+        assert_eq!(
+            volcano.max_flow_together(26, 2).unwrap_err().to_string(),
+            "17 valves have some flow, but splitting up more than 16 takes too long"
+        );
+    }
+
+    #[test]
+    fn every_submask() {
+        let mut subs: Vec<usize> = submasks(0b1010).collect();
+        subs.sort();
+        assert_eq!(subs, vec![0b0000, 0b0010, 0b1000, 0b1010]);
+        assert_eq!(submasks(0).collect::<Vec<_>>(), vec![0]);
+    }
+}
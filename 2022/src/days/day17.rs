@@ -0,0 +1,399 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use aoc::{cycle, Heading, Input, Solution};
+
+pub struct Day17 {
+    jets: Vec<Heading>,
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let jets = parse_input(input)?;
+        Ok(Self { jets })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(simulate(self.jets.clone(), 2022))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(simulate(self.jets.clone(), 1_000_000_000_000))
+    }
+}
+
+/// Jets only ever blow east or west.
+fn parse_input(text: &str) -> anyhow::Result<Vec<Heading>> {
+    let jets: Vec<Heading> = text
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '<' => Ok(Heading::West),
+            '>' => Ok(Heading::East),
+            other => bail!("unknown jet: {:?}", other),
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    if jets.is_empty() {
+        bail!("no jets");
+    }
+    Ok(jets)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Piece {
+    Dash,
+    Plus,
+    Corner,
+    Line,
+    Square,
+}
+
+impl Piece {
+    // TODO(variant_count): std::mem::variant_count::<Piece>()
+    const NUM_SHAPES: usize = 5;
+
+    const ALL: [Piece; Self::NUM_SHAPES] = {
+        use Piece::*;
+        [Dash, Plus, Corner, Line, Square]
+    };
+
+    fn new_rows(&self) -> Vec<Row> {
+        #[rustfmt::skip]
+        let rows: Vec<&str> = match self {
+            Piece::Dash => vec![
+                "..####.",
+            ],
+            Piece::Plus => vec![
+                "...#...",
+                "..###..",
+                "...#...",
+            ],
+            Piece::Corner => vec![
+                "....#..",
+                "....#..",
+                "..###..",
+            ],
+            Piece::Line => vec![
+                "..#....",
+                "..#....",
+                "..#....",
+                "..#....",
+            ],
+            Piece::Square => vec![
+                "..##...",
+                "..##...",
+            ],
+        };
+
+        rows.iter()
+            .rev() // Bottom-up!
+            .map(|s| Row::parse(s))
+            .collect()
+    }
+}
+
+// Stored bottom-up!
+#[derive(Debug, Clone)]
+struct Block(Vec<Row>);
+
+impl Block {
+    fn new(p: Piece) -> Self {
+        Self(p.new_rows())
+    }
+
+    fn push(&self, dir: Heading) -> Option<Self> {
+        let rows = Row::push_all(&self.0, dir);
+        rows.map(Self)
+    }
+}
+
+/// One row of the chamber, as a bitmask. Column 0 (the left wall side) is the high bit, so the
+/// bits read the same way the rows are drawn.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Row(u8);
+
+impl Row {
+    const EMPTY: Self = Self(0);
+    const WIDTH: usize = 7;
+
+    const LEFT_EDGE: u8 = 1 << (Self::WIDTH - 1);
+    const RIGHT_EDGE: u8 = 1;
+
+    fn parse(s: &str) -> Self {
+        Self(
+            s.chars()
+                .fold(0, |bits, c| (bits << 1) | u8::from(c == '#')),
+        )
+    }
+
+    fn has(&self, col: usize) -> bool {
+        self.0 & (Self::LEFT_EDGE >> col) != 0
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        assert!(
+            !self.intersects(other),
+            "merging piece that collides with tower"
+        );
+        Self(self.0 | other.0)
+    }
+
+    fn push(&self, dir: Heading) -> Result<Self, ()> {
+        match dir {
+            // Touching the edge, can't move.
+            Heading::West if self.0 & Self::LEFT_EDGE != 0 => Err(()),
+            Heading::East if self.0 & Self::RIGHT_EDGE != 0 => Err(()),
+
+            Heading::West => Ok(Self(self.0 << 1)),
+            Heading::East => Ok(Self(self.0 >> 1)),
+            Heading::North | Heading::South => unreachable!("jets only blow sideways"),
+        }
+    }
+
+    fn push_all(rs: &[Self], dir: Heading) -> Option<Vec<Self>> {
+        let pushed: Result<Vec<Self>, ()> = rs.iter().map(|r| r.push(dir)).collect();
+        pushed.ok()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tower {
+    grid: Vec<Row>,
+    pieces: usize,
+}
+
+impl Tower {
+    fn new() -> Self {
+        Self {
+            grid: vec![],
+            pieces: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn spawn(&mut self, piece: Piece, jets: &mut impl Iterator<Item = Heading>) -> usize {
+        let mut block = Block::new(piece);
+        let mut y = self.grid.len() + 3;
+
+        self.grid.extend([Row::EMPTY; 4]);
+
+        let mut jets_used = 0;
+        loop {
+            // Push!
+            let dir = jets.next().expect("jets repeat forever");
+            jets_used += 1;
+            if let Some(new_block) = block.push(dir) {
+                if self.can_place(y, &new_block) {
+                    block = new_block;
+                }
+            };
+
+            // Fall!
+            let Some(new_y) = y.checked_sub(1) else {
+                break;
+            };
+            if self.can_place(new_y, &block) {
+                y = new_y;
+            } else {
+                break;
+            }
+        }
+
+        self.place_at(y, block);
+        self.clean();
+
+        self.pieces += 1;
+        jets_used
+    }
+
+    fn clean(&mut self) {
+        // Garbage-collect empty rows.
+        let saved = self.grid.len();
+        let empty = self.grid.iter().rev().take_while(|r| r.is_empty()).count();
+        self.grid.truncate(saved - empty);
+    }
+
+    /// Columns deeper than this (or still empty) all look the same to [`Tower::profile`]. Pieces
+    /// don't fall that far down a single column in practice, and capping it keeps the profile from
+    /// growing forever when a column never gets any rock.
+    const MAX_DEPTH: usize = 64;
+
+    /// How far down from the top each column's highest rock is. New pieces can't fall any further
+    /// than that, so this is all that matters about the tower for what happens next.
+    fn profile(&self) -> [usize; Row::WIDTH] {
+        let mut depths = [Self::MAX_DEPTH; Row::WIDTH];
+        for (col, depth) in depths.iter_mut().enumerate() {
+            let top = self.grid.iter().rev().take(Self::MAX_DEPTH);
+            if let Some(d) = top.clone().position(|row| row.has(col)) {
+                *depth = d;
+            }
+        }
+        depths
+    }
+
+    fn can_place(&self, y: usize, block: &Block) -> bool {
+        let mut window = self.grid[y..].iter().zip(&block.0);
+        !window.any(|(tower, piece)| tower.intersects(piece))
+    }
+
+    fn place_at(&mut self, y: usize, block: Block) {
+        for (yy, p) in (y..).zip(block.0) {
+            let tower = self.grid[yy];
+            self.grid[yy] = tower.merge(&p);
+        }
+    }
+}
+
+fn simulate(jets: Vec<Heading>, rock_count: usize) -> usize {
+    let start = Sim {
+        jets: &jets,
+        tower: Tower::new(),
+        jet: 0,
+    };
+
+    let height = cycle::extrapolate(
+        start,
+        Sim::step,
+        Sim::key,
+        |sim| sim.tower.height() as i64,
+        rock_count,
+    );
+    height as usize
+}
+
+/// A tower partway through, and where it's up to in the jet pattern.
+#[derive(Debug, Clone)]
+struct Sim<'a> {
+    jets: &'a [Heading],
+    tower: Tower,
+    jet: usize,
+}
+
+impl Sim<'_> {
+    /// Drops one more piece.
+    fn step(&mut self) {
+        let piece = Piece::ALL[self.tower.pieces % Piece::NUM_SHAPES];
+        let mut jets = self.jets.iter().cloned().cycle().skip(self.jet);
+
+        let jets_used = self.tower.spawn(piece, &mut jets);
+        self.jet = (self.jet + jets_used) % self.jets.len();
+    }
+
+    fn key(&self) -> (usize, usize, [usize; Row::WIDTH]) {
+        (
+            self.tower.pieces % Piece::NUM_SHAPES,
+            self.jet,
+            self.tower.profile(),
+        )
+    }
+}
+
+impl Tower {
+    #[allow(dead_code)]
+    fn render(&self) {
+        // TODO(int_log): self.0.checked_log10().unwrap_or(0) + 1
+        let width = format!("{}", self.height()).chars().count();
+
+        for (i, row) in self.grid.iter().enumerate().rev() {
+            println!("{: >width$} {}", i, row);
+        }
+        println!("  -------");
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for col in 0..Self::WIDTH {
+            let c = if self.has(col) { '#' } else { '.' };
+            write!(f, "{}", c)?
+        }
+        Ok(())
+    }
+}
+
+aoc::example_tests! {
+    Day17,
+    part1 = "3068",
+    part2 = "1514285714288",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn example_cycle() {
+        let jets = parse_input(EXAMPLE).unwrap();
+        let start = Sim {
+            jets: &jets,
+            tower: Tower::new(),
+            jet: 0,
+        };
+
+        fn step<'a>(sim: &Sim<'a>) -> Sim<'a> {
+            let mut next = sim.clone();
+            next.step();
+            next
+        }
+        let found = cycle::brent(&start, step, Sim::key);
+        assert_eq!(found, cycle::floyd(&start, step, Sim::key));
+        assert_eq!(found.period, 35);
+
+        assert_eq!(simulate(jets.clone(), 2022), 3068);
+        assert_eq!(simulate(jets, 1_000_000_000_000), 1514285714288);
+    }
+
+    #[test]
+    fn empty_columns() {
+        // Nothing ever lands in the leftmost column, so only the cap lets this find a cycle.
+        let jets = parse_input(">>><<").unwrap();
+
+        let mut sim = Sim {
+            jets: &jets,
+            tower: Tower::new(),
+            jet: 0,
+        };
+        for _ in 0..2022 {
+            sim.step();
+        }
+
+        assert!(!sim.tower.grid.iter().any(|row| row.has(0)));
+
+        assert_eq!(simulate(jets.clone(), 2022), sim.tower.height());
+        simulate(jets, 1_000_000_000_000);
+    }
+
+    #[test]
+    fn row_bits() {
+        let row = Row::parse("..###..");
+        assert_eq!(row.to_string(), "..###..");
+
+        let left = row.push(Heading::West).and_then(|r| r.push(Heading::West));
+        assert_eq!(left.map(|r| r.to_string()), Ok("###....".to_string()));
+        assert!(left.unwrap().push(Heading::West).is_err());
+        assert_eq!(Row::parse("......#").push(Heading::East), Err(()));
+
+        assert!(row.intersects(&Row::parse("....#..")));
+        assert!(!row.intersects(&Row::parse("#.....#")));
+        assert_eq!(row.merge(&Row::parse("#......")).to_string(), "#.###..");
+    }
+}
@@ -1,12 +1,30 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 
-const INPUT: &str = include_str!("../../input/day18.txt");
+use aoc::{search, Input, Solution};
 
-fn main() {
-    let cubes = parse(INPUT);
-    let open_faces = part1(cubes.clone());
-    println!("Part 1: {}", open_faces);
-    println!("Part 2: {}", part2(cubes, open_faces));
+pub struct Day18 {
+    cubes: Vec<Cube>,
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let cubes = parse(input)?;
+        Ok(Self { cubes })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(self.cubes.clone()))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        let open_faces = part1(self.cubes.clone());
+        Ok(part2(self.cubes.clone(), open_faces))
+    }
 }
 
 fn part1(cubes: Vec<Cube>) -> usize {
@@ -25,8 +43,13 @@ fn part1(cubes: Vec<Cube>) -> usize {
 }
 
 fn part2(cubes: Vec<Cube>, open_faces: usize) -> usize {
-    let min = cubes.iter().map(Cube::min_coord).min().unwrap();
-    let max = cubes.iter().map(Cube::max_coord).max().unwrap();
+    let (Some(min), Some(max)) = (
+        cubes.iter().map(Cube::min_coord).min(),
+        cubes.iter().map(Cube::max_coord).max(),
+    ) else {
+        // No lava, no surface.
+        return 0;
+    };
 
     let mut grid: HashMap<Cube, Material> = cubes
         .iter()
@@ -67,7 +90,7 @@ fn part2(cubes: Vec<Cube>, open_faces: usize) -> usize {
 }
 
 fn steam(grid: &mut HashMap<Cube, Material>, lo: Cube, hi: Cube) {
-    let mut queue: VecDeque<Cube> = grid
+    let sources: Vec<Cube> = grid
         .iter()
         .filter_map(|(&cube, &mat)| {
             if mat == Material::Steam {
@@ -78,21 +101,15 @@ fn steam(grid: &mut HashMap<Cube, Material>, lo: Cube, hi: Cube) {
         })
         .collect();
 
-    while let Some(src) = queue.pop_front() {
-        for dst in src.neighbors() {
-            if dst.out_of_bounds(lo, hi) {
-                continue;
-            }
-
-            if let Some(_mat) = grid.get(&dst) {
-                continue;
-            }
-
-            let old = grid.insert(dst, Material::Steam);
-            assert!(old.is_none());
+    let steamed = search::reachable(sources, |src| {
+        src.neighbors()
+            .into_iter()
+            .filter(|dst| !dst.out_of_bounds(lo, hi) && !grid.contains_key(dst))
+            .collect::<Vec<_>>()
+    });
 
-            queue.push_back(dst);
-        }
+    for cube in steamed {
+        grid.entry(cube).or_insert(Material::Steam);
     }
 }
 
@@ -110,16 +127,11 @@ enum Material {
     Air,
 }
 
-fn parse(input: &str) -> Vec<Cube> {
-    aoc::lines(input)
-        .map(|line| {
-            let coords: Vec<&str> = line.split(',').collect();
-            let x = coords[0].parse().unwrap();
-            let y = coords[1].parse().unwrap();
-            let z = coords[2].parse().unwrap();
-            Cube::new(x, y, z)
-        })
-        .collect()
+fn parse(input: &str) -> anyhow::Result<Vec<Cube>> {
+    aoc::error::lines(input, |line| {
+        let (x, y, z) = aoc::scan!(line, "{},{},{}", i32, i32, i32)?;
+        Ok(Cube::new(x, y, z))
+    })
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -150,7 +162,7 @@ impl Cube {
     }
 
     fn neighbors(&self) -> Vec<Self> {
-        let deltas = vec![
+        let deltas = [
             (-1, 0, 0),
             (0, -1, 0),
             (0, 0, -1),
@@ -170,7 +182,7 @@ impl Cube {
     }
 
     fn out_of_bounds(&self, lo: Self, hi: Self) -> bool {
-        vec![
+        [
             self.x < lo.x,
             self.y < lo.y,
             self.z < lo.z,
@@ -182,3 +194,9 @@ impl Cube {
         .any(|b| *b)
     }
 }
+
+aoc::example_tests! {
+    Day18,
+    part1 = "64",
+    part2 = "58",
+}
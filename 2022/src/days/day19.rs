@@ -0,0 +1,345 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::bail;
+use aoc::{Input, Solution};
+use serde::{
+    de::{value, IntoDeserializer},
+    Deserialize,
+};
+
+pub struct Day19 {
+    blueprints: Vec<Blueprint>,
+}
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let blueprints = aoc::error::lines(input, Blueprint::parse)?;
+        Ok(Self { blueprints })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        Ok(part1(&self.blueprints))
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        // The example only has two blueprints.
+        let n = self.blueprints.len().min(3);
+        Ok(part2(&self.blueprints[..n]))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Blueprint {
+    id: usize,
+    /// What each type of robot costs, by resource.
+    costs: [Items; 4],
+}
+
+/// An amount of each resource (or robots mining each resource), in `Resource::ALL` order.
+type Items = [u32; 4];
+
+impl Blueprint {
+    fn parse(line: &str) -> anyhow::Result<Self> {
+        let (id, recipes) = aoc::scan!(line, "Blueprint {}: {}", usize, String)?;
+        let recipes = Recipe::parse_all(&recipes)?;
+
+        let mut costs = [[0; 4]; 4];
+        for resource in Resource::ALL {
+            let Some(recipe) = recipes.iter().find(|r| r.resource == resource) else {
+                bail!("no recipe for {:?} robots", resource);
+            };
+            for (&res, &n) in &recipe.costs {
+                costs[resource as usize][res as usize] = n;
+            }
+        }
+
+        Ok(Self { id, costs })
+    }
+
+    fn id(&self) -> u64 {
+        self.id as u64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Recipe {
+    resource: Resource,
+    costs: HashMap<Resource, u32>,
+}
+
+impl Recipe {
+    fn parse_all(text: &str) -> anyhow::Result<Vec<Self>> {
+        let mut recipes = vec![];
+        for sentence in text.split_terminator('.').map(str::trim) {
+            let (resource, costs) =
+                aoc::scan!(sentence, "Each {} robot costs {}", Resource, String)?;
+
+            let mut costs_by_resource = HashMap::new();
+            for phrase in costs.split(" and ") {
+                let (n, res) = aoc::scan!(phrase, "{} {}", u32, Resource)?;
+
+                if costs_by_resource.insert(res, n).is_some() {
+                    bail!("{:?} robots cost {:?} twice", resource, res);
+                }
+            }
+
+            recipes.push(Self {
+                resource,
+                costs: costs_by_resource,
+            });
+        }
+
+        Ok(recipes)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    const ALL: [Resource; 4] = [Self::Ore, Self::Clay, Self::Obsidian, Self::Geode];
+}
+
+impl FromStr for Resource {
+    type Err = value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
+fn part1(blueprints: &[Blueprint]) -> u64 {
+    let plans = plans(blueprints, 24);
+    blueprints
+        .iter()
+        .zip(plans)
+        .map(|(bp, plan)| bp.id() * u64::from(plan.geodes))
+        .sum()
+}
+
+fn part2(blueprints: &[Blueprint]) -> u64 {
+    plans(blueprints, 32)
+        .into_iter()
+        .map(|plan| u64::from(plan.geodes))
+        .product()
+}
+
+/// The best plan for each blueprint, worked out in parallel.
+fn plans(blueprints: &[Blueprint], total_minutes: u32) -> Vec<Plan> {
+    std::thread::scope(|s| {
+        let handles: Vec<_> = blueprints
+            .iter()
+            .map(|bp| s.spawn(move || bp.best_plan(total_minutes)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+/// Which robots to build, and in which minute, to crack the most geodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Plan {
+    geodes: u32,
+    builds: Vec<(u32, Resource)>,
+}
+
+impl Blueprint {
+    fn best_plan(&self, total_minutes: u32) -> Plan {
+        // There's no point making more of anything per minute than can be spent in a minute, since
+        // only one robot gets built at a time.
+        let mut max_useful = [u32::MAX; 4];
+        for res in [Resource::Ore, Resource::Clay, Resource::Obsidian] {
+            max_useful[res as usize] = self.costs.iter().map(|c| c[res as usize]).max().unwrap();
+        }
+
+        let search = Search {
+            blueprint: self,
+            max_useful,
+            total_minutes,
+        };
+
+        let start = State {
+            minutes_left: total_minutes,
+            robots: [1, 0, 0, 0],
+            items: [0; 4],
+        };
+        let mut best = Plan::default();
+        search.visit(start, &mut vec![], &mut best);
+        best
+    }
+}
+
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    max_useful: Items,
+    total_minutes: u32,
+}
+
+impl Search<'_> {
+    fn visit(&self, state: State, builds: &mut Vec<(u32, Resource)>, best: &mut Plan) {
+        // Even if nothing else gets built, the geode robots keep going.
+        let geodes = state.idle_geodes();
+        if geodes > best.geodes {
+            *best = Plan {
+                geodes,
+                builds: builds.clone(),
+            };
+        }
+
+        if state.optimistic_geodes(self.blueprint) <= best.geodes {
+            // This branch of the tree can't possibly do better.
+            return;
+        }
+
+        // Geode robots first, since they tend to lead to good plans sooner (and so more pruning).
+        for robot in Resource::ALL.into_iter().rev() {
+            if state.robots[robot as usize] >= self.max_useful[robot as usize] {
+                continue;
+            }
+            let Some(next) = state.build(self.blueprint, robot) else {
+                continue;
+            };
+
+            // The minute it took to build is already over.
+            builds.push((self.total_minutes - next.minutes_left, robot));
+            self.visit(next, builds, best);
+            builds.pop();
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct State {
+    minutes_left: u32,
+    robots: Items,
+    items: Items,
+}
+
+impl State {
+    fn geodes(&self) -> u32 {
+        self.items[Resource::Geode as usize]
+    }
+
+    fn idle_geodes(&self) -> u32 {
+        self.geodes() + self.robots[Resource::Geode as usize] * self.minutes_left
+    }
+
+    /// Waits until the robot can be built, then builds it, unless it wouldn't be done in time to
+    /// be any use.
+    fn build(&self, blueprint: &Blueprint, robot: Resource) -> Option<Self> {
+        let costs = &blueprint.costs[robot as usize];
+
+        let mut wait = 0;
+        for ((&cost, &have), &rate) in costs.iter().zip(&self.items).zip(&self.robots) {
+            let missing = cost.saturating_sub(have);
+            if missing > 0 {
+                if rate == 0 {
+                    return None;
+                }
+                wait = wait.max(missing.div_ceil(rate));
+            }
+        }
+
+        // The robot needs at least one minute after it's built to mine anything.
+        if wait + 1 >= self.minutes_left {
+            return None;
+        }
+
+        let mut next = self.clone();
+        for ((have, &rate), &cost) in next.items.iter_mut().zip(&self.robots).zip(costs) {
+            *have = *have + rate * (wait + 1) - cost;
+        }
+        next.robots[robot as usize] += 1;
+        next.minutes_left -= wait + 1;
+
+        Some(next)
+    }
+
+    /// An upper bound on the geodes: pretend ore and clay are free, so an obsidian robot gets built
+    /// every minute, and a geode robot too whenever there's enough obsidian.
+    fn optimistic_geodes(&self, blueprint: &Blueprint) -> u32 {
+        let obsidian_cost = blueprint.costs[Resource::Geode as usize][Resource::Obsidian as usize];
+
+        let mut obsidian = self.items[Resource::Obsidian as usize];
+        let mut geodes = self.geodes();
+        let mut geode_robots = self.robots[Resource::Geode as usize];
+
+        let obsidian_robots = self.robots[Resource::Obsidian as usize]..;
+        for (obsidian_robots, _) in obsidian_robots.zip(0..self.minutes_left) {
+            let build = obsidian >= obsidian_cost;
+
+            obsidian += obsidian_robots;
+            geodes += geode_robots;
+
+            if build {
+                obsidian -= obsidian_cost;
+                geode_robots += 1;
+            }
+        }
+
+        geodes
+    }
+}
+
+aoc::example_tests! {
+    Day19,
+    part1 = "33",
+    part2 = "3472",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 2] = [
+        "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.",
+        "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.",
+    ];
+
+    #[test]
+    fn build_order() {
+        let blueprint = Blueprint::parse(EXAMPLE[0]).unwrap();
+        let plan = blueprint.best_plan(24);
+
+        // The same as the walkthrough in the puzzle.
+        use Resource::*;
+        assert_eq!(plan.geodes, 9);
+        assert_eq!(
+            plan.builds,
+            vec![
+                (3, Clay),
+                (5, Clay),
+                (7, Clay),
+                (11, Obsidian),
+                (12, Clay),
+                (15, Obsidian),
+                (18, Geode),
+                (21, Geode),
+            ]
+        );
+
+        let other = Blueprint::parse(EXAMPLE[1]).unwrap();
+        assert_eq!(other.best_plan(24).geodes, 12);
+
+        let mut state = State {
+            minutes_left: 24,
+            robots: [1, 0, 0, 0],
+            items: [0; 4],
+        };
+        for &(minute, robot) in &plan.builds {
+            state = state.build(&blueprint, robot).unwrap();
+            assert_eq!(24 - state.minutes_left, minute);
+        }
+        assert_eq!(state.idle_geodes(), plan.geodes);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context};
+use aoc::{Input, Solution};
+
+pub struct Day20 {
+    ciphertext: Vec<i64>,
+}
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let ciphertext = parse_input(input)?;
+        Ok(Self { ciphertext })
+    }
+
+    fn part1(&self) -> anyhow::Result<i64> {
+        Ok(part1(self.ciphertext.clone()))
+    }
+
+    fn part2(&self) -> anyhow::Result<i64> {
+        part2(self.ciphertext.clone())
+    }
+}
+
+fn parse_input(text: &str) -> anyhow::Result<Vec<i64>> {
+    let ciphertext: Vec<i64> = aoc::error::lines(text, |line| Ok(line.parse()?))?;
+    if ciphertext.len() < 2 {
+        bail!(
+            "need at least two numbers to mix, found {}",
+            ciphertext.len()
+        );
+    }
+    if !ciphertext.contains(&0) {
+        bail!("no 0 in the file to start the grove coordinates from");
+    }
+    Ok(ciphertext)
+}
+
+fn part1(ciphertext: Vec<i64>) -> i64 {
+    let mixed = mix(ciphertext, 1);
+    grove_hash(mixed)
+}
+
+fn part2(ciphertext: Vec<i64>) -> anyhow::Result<i64> {
+    let decryption_key: i64 = 811589153;
+    let keyed: Vec<i64> = ciphertext
+        .iter()
+        .map(|&n| n.checked_mul(decryption_key).context("too big to decrypt"))
+        .collect::<anyhow::Result<_>>()?;
+
+    let mixed = mix(keyed, 10);
+    Ok(grove_hash(mixed))
+}
+
+fn mix(ciphertext: Vec<i64>, times: usize) -> Vec<i64> {
+    let mut decrypter = Decrypter::new(&ciphertext);
+
+    for _ in 0..times {
+        for (i, &n) in ciphertext.iter().enumerate() {
+            decrypter.mv(i, n);
+        }
+    }
+
+    decrypter.read()
+}
+
+fn grove_hash(msg: Vec<i64>) -> i64 {
+    let zero = msg
+        .iter()
+        .position(|&n| n == 0)
+        .expect("Day20::parse checks for a 0");
+    let get = |i: usize| -> i64 { msg[(i + zero) % msg.len()] };
+
+    get(1_000) + get(2_000) + get(3_000)
+}
+
+#[derive(Debug, Clone)]
+struct Decrypter {
+    message: Vec<i64>,
+    ptr: VecDeque<usize>,
+}
+
+impl Decrypter {
+    fn new(ciphertext: &[i64]) -> Self {
+        Self {
+            message: ciphertext.to_vec(),
+            ptr: (0..ciphertext.len()).collect(),
+        }
+    }
+
+    fn read(&self) -> Vec<i64> {
+        self.ptr.iter().map(|&p| self.message[p]).collect()
+    }
+
+    fn mv(&mut self, i: usize, n: i64) {
+        let p = self.ptr.iter().position(|&p| p == i).unwrap();
+
+        // [a, b, c, i, x, y, z]
+        self.ptr.rotate_left(p);
+        // [i, x, y, z, a, b, c]
+
+        let m = self.ptr.pop_front().unwrap();
+        // i | [x, y, z, a, b, c]
+        assert_eq!(i, m);
+
+        // It's a circle, so moving back is just moving forward the rest of the way around.
+        let n = imod(n, self.ptr.len());
+        self.ptr.rotate_left(n);
+        self.ptr.push_front(m);
+    }
+}
+
+fn imod(n: i64, m: usize) -> usize {
+    let m: i64 = m.try_into().expect("the message fits in memory");
+    n.rem_euclid(m) as usize
+}
+
+aoc::example_tests! {
+    Day20,
+    part1 = "3",
+    part2 = "1623178306",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_messages() {
+        let err = |text| parse_input(text).unwrap_err().to_string();
+        assert_eq!(err("0\n"), "need at least two numbers to mix, found 1");
+
+        let ciphertext = parse_input("0\n-9223372036854775808\n").unwrap();
+        assert_eq!(part1(ciphertext.clone()), 0);
+        assert!(part2(ciphertext).is_err());
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, bail};
+use aoc::{Input, Solution};
+
+pub struct Day21 {
+    monkeys: HashMap<Name, Expr>,
+}
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let monkeys = parse(input)?;
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> anyhow::Result<i128> {
+        part1(&self.monkeys)
+    }
+
+    fn part2(&self) -> anyhow::Result<i128> {
+        part2(&self.monkeys)
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<HashMap<Name, Expr>> {
+    let monkeys: HashMap<Name, Expr> = aoc::error::lines(input, |line| {
+        let (name, expr) = aoc::scan!(line, "{}: {}", Name, String)?;
+        Ok((name, Expr::parse(&expr)?))
+    })?
+    .into_iter()
+    .collect();
+
+    if !monkeys.contains_key("root") {
+        bail!("no root monkey");
+    }
+    for (name, expr) in &monkeys {
+        for other in expr.bindings() {
+            if !monkeys.contains_key(&other) {
+                bail!("{} listens for {}, but there's no such monkey", name, other);
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+type Name = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Expr {
+    Const(i128),
+    Monkey(Name),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        let w: Vec<&str> = s.split(' ').collect();
+
+        match w.as_slice() {
+            [atom] => Ok(Expr::parse_atom(atom)),
+            [l, op, r] => Expr::parse_binary(l, op, r),
+            _ => bail!("expected a number, a name, or a binary operation: {:?}", s),
+        }
+    }
+
+    fn parse_atom(s: &str) -> Self {
+        match s.parse::<i128>() {
+            Ok(u) => Expr::Const(u),
+            Err(_) => Expr::Monkey(s.to_string()),
+        }
+    }
+
+    fn parse_binary(l: &str, op: &str, r: &str) -> anyhow::Result<Self> {
+        let l = Box::new(Self::parse_atom(l));
+        let r = Box::new(Self::parse_atom(r));
+
+        Ok(match op {
+            "+" => Expr::Add(l, r),
+            "-" => Expr::Sub(l, r),
+            "*" => Expr::Mul(l, r),
+            "/" => Expr::Div(l, r),
+            other => bail!("unknown operator: {:?}", other),
+        })
+    }
+}
+
+impl Expr {
+    fn bindings(&self) -> Vec<Name> {
+        match self {
+            Expr::Const(_) => vec![],
+            Expr::Monkey(name) => vec![name.clone()],
+            Expr::Add(l, r) => [l.bindings(), r.bindings()].concat(),
+            Expr::Sub(l, r) => [l.bindings(), r.bindings()].concat(),
+            Expr::Mul(l, r) => [l.bindings(), r.bindings()].concat(),
+            Expr::Div(l, r) => [l.bindings(), r.bindings()].concat(),
+        }
+    }
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Const(v) => write!(f, "{}", v),
+            Expr::Monkey(name) => write!(f, "{}", name),
+            Expr::Add(l, r) => write!(f, "{} + {}", l, r),
+            Expr::Sub(l, r) => write!(f, "{} - {}", l, r),
+            Expr::Mul(l, r) => write!(f, "{} * {}", l, r),
+            Expr::Div(l, r) => write!(f, "{} / {}", l, r),
+        }
+    }
+}
+
+fn part1(monkeys: &HashMap<Name, Expr>) -> anyhow::Result<i128> {
+    let root = Solver::new(monkeys, None).eval("root")?.constant_part();
+    root.integer_value()
+        .ok_or_else(|| anyhow!("root yells {}, which isn't a whole number", root))
+}
+
+fn part2(monkeys: &HashMap<Name, Expr>) -> anyhow::Result<i128> {
+    if !monkeys.contains_key(HUMAN) {
+        bail!("no {} monkey", HUMAN);
+    }
+
+    let (l, r) = match &monkeys["root"] {
+        Expr::Add(l, r) | Expr::Sub(l, r) | Expr::Mul(l, r) | Expr::Div(l, r) => (l, r),
+        other => bail!(
+            "root should compare two monkeys, found {:?}",
+            other.to_string()
+        ),
+    };
+
+    let mut solver = Solver::new(monkeys, Some(HUMAN));
+    let l = solver.eval_expr("root", l)?;
+    let r = solver.eval_expr("root", r)?;
+
+    // a * humn + b == 0
+    let Linear { a, b } = l.sub(r)?;
+    if a == Ratio::ZERO {
+        if b == Ratio::ZERO {
+            bail!("root's numbers match no matter what {} yells", HUMAN);
+        }
+        bail!("root's numbers never match, no matter what {} yells", HUMAN);
+    }
+
+    let x = b.neg()?.div(a)?;
+    x.integer_value().ok_or_else(|| {
+        anyhow!(
+            "root's numbers only match if {} yells {}, which isn't a whole number",
+            HUMAN,
+            x
+        )
+    })
+}
+
+/// Me!
+const HUMAN: &str = "humn";
+
+/// Works out what each monkey yells, in terms of the unknown (if there is one).
+struct Solver<'a> {
+    monkeys: &'a HashMap<Name, Expr>,
+    unknown: Option<&'a str>,
+    memo: HashMap<Name, Linear>,
+    /// The monkeys still waiting on other monkeys, to catch any that end up waiting on themselves.
+    waiting: HashSet<Name>,
+}
+
+impl<'a> Solver<'a> {
+    fn new(monkeys: &'a HashMap<Name, Expr>, unknown: Option<&'a str>) -> Self {
+        Self {
+            monkeys,
+            unknown,
+            memo: HashMap::new(),
+            waiting: HashSet::new(),
+        }
+    }
+
+    fn eval(&mut self, name: &str) -> anyhow::Result<Linear> {
+        if self.unknown == Some(name) {
+            return Ok(Linear::UNKNOWN);
+        }
+        if let Some(&v) = self.memo.get(name) {
+            return Ok(v);
+        }
+        if !self.waiting.insert(name.to_string()) {
+            bail!("{} ends up waiting on itself", name);
+        }
+
+        let v = self.eval_expr(name, &self.monkeys[name])?;
+
+        self.waiting.remove(name);
+        self.memo.insert(name.to_string(), v);
+        Ok(v)
+    }
+
+    fn eval_expr(&mut self, name: &str, expr: &Expr) -> anyhow::Result<Linear> {
+        let (l, r) = match expr {
+            Expr::Const(v) => return Ok(Linear::constant(Ratio::integer(*v))),
+            Expr::Monkey(other) => return self.eval(other),
+            Expr::Add(l, r) | Expr::Sub(l, r) | Expr::Mul(l, r) | Expr::Div(l, r) => {
+                (self.eval_expr(name, l)?, self.eval_expr(name, r)?)
+            }
+        };
+
+        match expr {
+            Expr::Add(..) => l.add(r),
+            Expr::Sub(..) => l.sub(r),
+            Expr::Mul(..) => l.mul(r),
+            Expr::Div(..) => l.div(r),
+            Expr::Const(_) | Expr::Monkey(_) => unreachable!(),
+        }
+        .map_err(|err| anyhow!("{} ({}): {}", name, expr, err))
+    }
+}
+
+/// `a * x + b`, for the unknown `x`. Everything that doesn't depend on the unknown simplifies down
+/// to `b`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Linear {
+    a: Ratio,
+    b: Ratio,
+}
+
+impl Linear {
+    const UNKNOWN: Self = Self {
+        a: Ratio::ONE,
+        b: Ratio::ZERO,
+    };
+
+    fn constant(b: Ratio) -> Self {
+        Self { a: Ratio::ZERO, b }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.a == Ratio::ZERO
+    }
+
+    /// What this works out to if it doesn't depend on the unknown (or the unknown is 0).
+    fn constant_part(&self) -> Ratio {
+        self.b
+    }
+
+    fn scale(self, k: Ratio) -> anyhow::Result<Self> {
+        Ok(Self {
+            a: self.a.mul(k)?,
+            b: self.b.mul(k)?,
+        })
+    }
+
+    fn add(self, other: Self) -> anyhow::Result<Self> {
+        Ok(Self {
+            a: self.a.add(other.a)?,
+            b: self.b.add(other.b)?,
+        })
+    }
+
+    fn sub(self, other: Self) -> anyhow::Result<Self> {
+        Ok(Self {
+            a: self.a.sub(other.a)?,
+            b: self.b.sub(other.b)?,
+        })
+    }
+
+    fn mul(self, other: Self) -> anyhow::Result<Self> {
+        if self.is_constant() {
+            other.scale(self.b)
+        } else if other.is_constant() {
+            self.scale(other.b)
+        } else {
+            bail!(
+                "the equation isn't linear: {} gets multiplied by itself",
+                HUMAN
+            );
+        }
+    }
+
+    fn div(self, other: Self) -> anyhow::Result<Self> {
+        if !other.is_constant() {
+            bail!(
+                "the equation isn't linear: something gets divided by {}",
+                HUMAN
+            );
+        }
+        self.scale(Ratio::ONE.div(other.b)?)
+    }
+}
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+impl Ratio {
+    const ZERO: Self = Self { num: 0, den: 1 };
+    const ONE: Self = Self { num: 1, den: 1 };
+
+    fn integer(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+
+    fn new(num: i128, den: i128) -> anyhow::Result<Self> {
+        if den == 0 {
+            bail!("division by zero");
+        }
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Ok(Self {
+                num: checked(num.checked_neg())?,
+                den: checked(den.checked_neg())?,
+            })
+        } else {
+            Ok(Self { num, den })
+        }
+    }
+
+    fn integer_value(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    fn neg(self) -> anyhow::Result<Self> {
+        Self::new(checked(self.num.checked_neg())?, self.den)
+    }
+
+    fn add(self, other: Self) -> anyhow::Result<Self> {
+        let l = checked(self.num.checked_mul(other.den))?;
+        let r = checked(other.num.checked_mul(self.den))?;
+        let den = checked(self.den.checked_mul(other.den))?;
+        Self::new(checked(l.checked_add(r))?, den)
+    }
+
+    fn sub(self, other: Self) -> anyhow::Result<Self> {
+        self.add(other.neg()?)
+    }
+
+    fn mul(self, other: Self) -> anyhow::Result<Self> {
+        let num = checked(self.num.checked_mul(other.num))?;
+        let den = checked(self.den.checked_mul(other.den))?;
+        Self::new(num, den)
+    }
+
+    fn div(self, other: Self) -> anyhow::Result<Self> {
+        if other.num == 0 {
+            bail!("division by zero");
+        }
+        self.mul(Self::new(other.den, other.num)?)
+    }
+}
+
+impl std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.integer_value() {
+            Some(n) => write!(f, "{}", n),
+            None => write!(f, "{}/{}", self.num, self.den),
+        }
+    }
+}
+
+fn checked(n: Option<i128>) -> anyhow::Result<i128> {
+    n.ok_or_else(|| anyhow!("the numbers got too big (over {})", i128::MAX))
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+aoc::example_tests! {
+    Day21,
+    part1 = "152",
+    part2 = "301",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monkeys(lines: &[&str]) -> HashMap<Name, Expr> {
+        parse(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn humn_twice() {
+        // (humn + humn) / 4 - 3 == humn - 10
+        let monkeys = monkeys(&[
+            "root: left + right",
+            "left: quarter - three",
+            "quarter: double / four",
+            "double: humn + humn",
+            "right: humn - ten",
+            "humn: 12",
+            "three: 3",
+            "four: 4",
+            "ten: 10",
+        ]);
+        assert_eq!(part1(&monkeys).unwrap(), 5);
+        assert_eq!(part2(&monkeys).unwrap(), 14);
+    }
+
+    #[test]
+    fn bad_equations() {
+        let err = |lines: &[&str]| part2(&monkeys(lines)).unwrap_err().to_string();
+
+        assert_eq!(
+            err(&["root: sq + one", "sq: humn * humn", "humn: 5", "one: 1"]),
+            "sq (humn * humn): the equation isn't linear: humn gets multiplied by itself"
+        );
+        assert_eq!(
+            err(&[
+                "root: one / ratio",
+                "ratio: two / humn",
+                "humn: 5",
+                "one: 1",
+                "two: 2"
+            ]),
+            "ratio (two / humn): the equation isn't linear: something gets divided by humn"
+        );
+        assert_eq!(
+            err(&[
+                "root: double + three",
+                "double: humn * two",
+                "humn: 5",
+                "two: 2",
+                "three: 3"
+            ]),
+            "root's numbers only match if humn yells 3/2, which isn't a whole number"
+        );
+        assert_eq!(
+            err(&[
+                "root: zero + one",
+                "zero: humn * nil",
+                "humn: 5",
+                "nil: 0",
+                "one: 1"
+            ]),
+            "root's numbers never match, no matter what humn yells"
+        );
+        assert_eq!(
+            err(&[
+                "root: humn + a",
+                "a: b + one",
+                "b: a + one",
+                "humn: 5",
+                "one: 1"
+            ]),
+            "a ends up waiting on itself"
+        );
+    }
+}
@@ -0,0 +1,757 @@
+use std::{iter::Peekable, str::Chars};
+
+use anyhow::{bail, ensure, Context};
+use aoc::{Heading, Input, Point3, Solution};
+
+pub struct Day22 {
+    board: Board,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let (board, moves) = parse(input)?;
+        Ok(Self { board, moves })
+    }
+
+    fn part1(&self) -> anyhow::Result<u64> {
+        part1(self.board.clone(), self.moves.clone())
+    }
+
+    fn part2(&self) -> anyhow::Result<u64> {
+        part2(self.board.clone(), self.moves.clone())
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<(Board, Vec<Move>)> {
+    let Some((board, moves)) = input.split_once("\n\n") else {
+        bail!("expected the board and the moves, separated by a blank line");
+    };
+
+    let board_lines = board.lines().count();
+    let board = Board::parse(board)?;
+
+    // The moves come after the board and a blank line.
+    let moves = aoc::error::line(&[moves.trim()], 0, Move::parse_all)
+        .map_err(|err| aoc::Error::from(err).after(board_lines + 1))?;
+
+    Ok((board, moves))
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    rows: Vec<Range>,
+    cols: Vec<Range>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    start: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Range {
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.tiles.len() - 1
+    }
+
+    fn at(&self, c: usize) -> Option<Tile> {
+        if self.start() <= c && c <= self.end() {
+            return Some(self.tiles[c - self.start]);
+        }
+        None
+    }
+}
+
+impl Board {
+    fn parse(text: &str) -> anyhow::Result<Self> {
+        let rows = Self::parse_rows(text)?;
+        let cols = Self::parse_cols(&rows);
+
+        let board = Self { rows, cols };
+        board.check()?;
+        board.start()?;
+        Ok(board)
+    }
+
+    fn parse_rows(text: &str) -> anyhow::Result<Vec<Range>> {
+        aoc::error::lines(text, |line| {
+            let start = line.len() - line.trim_start_matches(' ').len();
+
+            let mut tiles = vec![];
+            for (c, fill) in line.char_indices().skip(start) {
+                match fill {
+                    '.' => tiles.push(Tile::Open),
+                    '#' => tiles.push(Tile::Wall),
+                    other => {
+                        let err = format!("expected '.' or '#', found {:?}", other);
+                        return Err(aoc::Error::new(err).with_column(c + 1).into());
+                    }
+                }
+            }
+
+            if tiles.is_empty() {
+                bail!("row has no tiles");
+            }
+
+            Ok(Range { start, tiles })
+        })
+    }
+
+    fn parse_cols(rows: &[Range]) -> Vec<Range> {
+        let col_max = rows.iter().map(|r| r.end()).max().unwrap_or(0);
+
+        let mut cols = vec![];
+
+        for c in 0..=col_max {
+            let mut start = 0;
+            for (r, row) in rows.iter().enumerate() {
+                if row.at(c).is_some() {
+                    start = r;
+                    break;
+                }
+            }
+
+            let mut tiles = vec![];
+            for row in &rows[start..] {
+                let Some(tile) = row.at(c) else {
+                    break;
+                };
+
+                tiles.push(tile);
+            }
+
+            cols.push(Range { start, tiles })
+        }
+
+        cols
+    }
+
+    /// Columns are only read top to bottom until the first gap, so anything after a gap would be
+    /// missing from them.
+    fn check(&self) -> anyhow::Result<()> {
+        if self.rows.is_empty() {
+            bail!("no board");
+        }
+        for (r, row) in self.rows.iter().enumerate() {
+            for (c, col) in self.cols.iter().enumerate() {
+                if row.at(c) != col.at(r) {
+                    bail!(
+                        "column {} has a gap in it before row {}, which can't be walked around",
+                        c + 1,
+                        r + 1
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The leftmost open tile of the top row.
+    fn start(&self) -> anyhow::Result<(usize, usize)> {
+        let top = &self.rows[0];
+        let Some(i) = top.tiles.iter().position(|&t| t == Tile::Open) else {
+            bail!("there's no open tile in the top row to start on");
+        };
+        Ok((0, top.start + i))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Move {
+    Forward(u64),
+    Left,
+    Right,
+}
+
+impl Move {
+    fn parse_all(line: &str) -> anyhow::Result<Vec<Self>> {
+        let mut chars = line.chars().peekable();
+        let mut moves = vec![];
+
+        let Some(first) = chars.peek() else {
+            bail!("no moves");
+        };
+
+        let mut mv = first.is_ascii_digit();
+        while chars.peek().is_some() {
+            if mv {
+                moves.push(Self::parse_walk(&mut chars));
+            } else {
+                let column = line.len() - chars.clone().count() + 1;
+                let turn = Self::parse_turn(&mut chars)
+                    .map_err(|err| aoc::Error::new(err).with_column(column))?;
+                moves.push(turn);
+            }
+            mv = !mv;
+        }
+        Ok(moves)
+    }
+
+    fn parse_walk(chars: &mut Peekable<Chars>) -> Self {
+        let mut n = 0;
+        while let Some(c) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+
+            let c = chars.next().unwrap();
+
+            n *= 10;
+            n += (c as u64) - ('0' as u64);
+        }
+        Move::Forward(n)
+    }
+
+    fn parse_turn(chars: &mut Peekable<Chars>) -> anyhow::Result<Self> {
+        Ok(match chars.next() {
+            Some('L') => Move::Left,
+            Some('R') => Move::Right,
+            Some(other) => bail!("expected a turn ('L' or 'R'), found {:?}", other),
+            None => bail!("expected a turn ('L' or 'R'), found the end of the line"),
+        })
+    }
+}
+
+fn part1(board: Board, moves: Vec<Move>) -> anyhow::Result<u64> {
+    let (mut r, mut c) = board.start()?;
+    let mut h = Heading::East;
+
+    for mv in moves {
+        match mv {
+            Move::Left => h = h.turn_left(),
+            Move::Right => h = h.turn_right(),
+            Move::Forward(steps) => (r, c) = board.walk(r, c, h, steps)?,
+        }
+    }
+
+    Ok(score(r, c, h))
+}
+
+fn score(r: usize, c: usize, h: Heading) -> u64 {
+    // 1 indexing
+    let r: u64 = (r + 1).try_into().unwrap();
+    let c: u64 = (c + 1).try_into().unwrap();
+
+    let h = match h {
+        Heading::East => 0,
+        Heading::South => 1,
+        Heading::West => 2,
+        Heading::North => 3,
+    };
+
+    1000 * r + 4 * c + h
+}
+
+impl Board {
+    fn walk(
+        &self,
+        r: usize,
+        c: usize,
+        h: Heading,
+        distance: u64,
+    ) -> anyhow::Result<(usize, usize)> {
+        let d: i64 = distance.try_into()?;
+        match h {
+            Heading::North => self.walk_vertical(r, c, -d),
+            Heading::South => self.walk_vertical(r, c, d),
+
+            Heading::East => self.walk_horizontal(r, c, d),
+            Heading::West => self.walk_horizontal(r, c, -d),
+        }
+        .with_context(|| format!("walking from row {}, column {}", r + 1, c + 1))
+    }
+
+    fn walk_horizontal(&self, r: usize, c: usize, dc: i64) -> anyhow::Result<(usize, usize)> {
+        let c = self.rows[r].walk(c, dc)?;
+        Ok((r, c))
+    }
+
+    fn walk_vertical(&self, r: usize, c: usize, dr: i64) -> anyhow::Result<(usize, usize)> {
+        let r = self.cols[c].walk(r, dr)?;
+        Ok((r, c))
+    }
+}
+
+impl Range {
+    fn walk(&self, offset: usize, delta: i64) -> anyhow::Result<usize> {
+        let to_walk: usize = delta.unsigned_abs().try_into()?;
+
+        let dest = self
+            .ring(offset, delta < 0)?
+            .iter()
+            .cloned()
+            .cycle()
+            .take(to_walk + 1) // Consume the start tile
+            .take_while(|&(_i, t)| t == Tile::Open)
+            .last();
+
+        Ok(match dest {
+            Some((i, _tile)) => i,
+            None => offset, // Didn't move
+        })
+    }
+
+    /// Every tile in the range, starting at the current position and wrapping around the end.
+    fn ring(&self, offset: usize, reverse: bool) -> anyhow::Result<Vec<(usize, Tile)>> {
+        ensure!(self.at(offset) == Some(Tile::Open), "it's not an open tile");
+
+        let mut ring: Vec<(usize, Tile)> = self
+            .tiles
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, t)| (i + self.start, t))
+            .collect();
+
+        if reverse {
+            ring.reverse();
+            ring.rotate_left(self.end() - offset);
+        } else {
+            ring.rotate_left(offset - self.start);
+        }
+
+        Ok(ring)
+    }
+
+    /// Like [`Range::ring`], but the walkway _does not_ wrap: it stops at the end of the range.
+    fn walkway(&self, offset: usize, reverse: bool) -> anyhow::Result<Vec<(usize, Tile)>> {
+        let mut walkway = self.ring(offset, reverse)?;
+        if reverse {
+            walkway.truncate(offset - self.start + 1);
+        } else {
+            walkway.truncate(self.end() - offset + 1);
+        }
+        Ok(walkway)
+    }
+}
+
+fn part2(board: Board, moves: Vec<Move>) -> anyhow::Result<u64> {
+    let cube = Cube::fold(&board)?;
+
+    let (mut r, mut c) = board.start()?;
+    let mut h = Heading::East;
+
+    for mv in moves {
+        match mv {
+            Move::Left => h = h.turn_left(),
+            Move::Right => h = h.turn_right(),
+            Move::Forward(mut steps) => {
+                'walk: while steps > 0 {
+                    // Finish current face
+                    let walkway = board.walkway(r, c, h)?;
+
+                    let mut ww = walkway.iter();
+                    // Consume the current tile.
+                    ww.next();
+
+                    'face: while steps > 0 {
+                        let Some(&(rr, cc, t)) = ww.next() else {
+                            break 'face;
+                        };
+
+                        if t == Tile::Wall {
+                            break 'walk;
+                        }
+
+                        (r, c) = (rr, cc);
+                        steps -= 1;
+                    }
+
+                    // Teleport to a new face
+                    if steps > 0 {
+                        let (rr, cc, hh, t) = cube.faceroll(&board, r, c, h);
+                        let (rrr, ccc, hhh, _) = cube.faceroll(&board, rr, cc, hh.flip());
+                        ensure!(
+                            (r, c, h) == (rrr, ccc, hhh.flip()),
+                            "walking off row {}, column {} heading {:?} doesn't fold back the same way",
+                            r + 1,
+                            c + 1,
+                            h
+                        );
+
+                        if t == Tile::Wall {
+                            break 'walk;
+                        }
+
+                        (r, c, h) = (rr, cc, hh);
+                        steps -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(score(r, c, h))
+}
+
+impl Board {
+    fn walkway(&self, r: usize, c: usize, h: Heading) -> anyhow::Result<Vec<(usize, usize, Tile)>> {
+        use Heading::*;
+
+        let walkway = match h {
+            North => self.cols[c].walkway(r, true).map(|w| along_col(c, w)),
+            South => self.cols[c].walkway(r, false).map(|w| along_col(c, w)),
+            East => self.rows[r].walkway(c, false).map(|w| along_row(r, w)),
+            West => self.rows[r].walkway(c, true).map(|w| along_row(r, w)),
+        };
+        walkway.with_context(|| format!("walking from row {}, column {}", r + 1, c + 1))
+    }
+}
+
+fn along_row(r: usize, walkway: Vec<(usize, Tile)>) -> Vec<(usize, usize, Tile)> {
+    walkway.into_iter().map(|(c, t)| (r, c, t)).collect()
+}
+
+fn along_col(c: usize, walkway: Vec<(usize, Tile)>) -> Vec<(usize, usize, Tile)> {
+    walkway.into_iter().map(|(r, t)| (r, c, t)).collect()
+}
+
+/// How the board folds up into a cube: which face (and heading) you end up on after walking off
+/// each edge of each face.
+#[derive(Debug, Clone)]
+struct Cube {
+    size: usize,
+    /// The top-left corner of each face on the board, in units of faces.
+    faces: Vec<(usize, usize)>,
+    /// For each face, and each heading in `Heading::ALL` order, where walking off that edge leads.
+    edges: Vec<[(usize, Heading); 4]>,
+}
+
+/// Which way a face ends up pointing once the net is folded up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Orientation {
+    normal: Point3<i32>,
+    east: Point3<i32>,
+    south: Point3<i32>,
+}
+
+impl Orientation {
+    /// The direction (in 3D) that walking with the heading goes along the face.
+    fn dir(&self, h: Heading) -> Point3<i32> {
+        match h {
+            Heading::North => -self.south,
+            Heading::East => self.east,
+            Heading::South => self.south,
+            Heading::West => -self.east,
+        }
+    }
+
+    /// The orientation of the face next to this one on the net, after folding it over the edge.
+    fn roll(self, h: Heading) -> Self {
+        let Self {
+            normal,
+            east,
+            south,
+        } = self;
+        match h {
+            Heading::North => Self {
+                normal: -south,
+                south: normal,
+                east,
+            },
+            Heading::East => Self {
+                normal: east,
+                east: -normal,
+                south,
+            },
+            Heading::South => Self {
+                normal: south,
+                south: -normal,
+                east,
+            },
+            Heading::West => Self {
+                normal: -east,
+                east: normal,
+                south,
+            },
+        }
+    }
+}
+
+fn index(h: Heading) -> usize {
+    Heading::ALL.iter().position(|&hh| hh == h).unwrap()
+}
+
+impl Cube {
+    fn fold(board: &Board) -> anyhow::Result<Self> {
+        let tiles: usize = board.rows.iter().map(|row| row.tiles.len()).sum();
+        let size = (1..).find(|n| 6 * n * n >= tiles).unwrap();
+        if 6 * size * size != tiles {
+            bail!(
+                "the board has {} tiles, which can't be six square faces",
+                tiles
+            );
+        }
+
+        // Every face must be completely on the board. Having exactly six of them then accounts for
+        // every tile, so there aren't any left over.
+        let mut faces = vec![];
+        for fr in 0..board.rows.len().div_ceil(size) {
+            let row = &board.rows[fr * size];
+            for fc in (row.start() / size)..=(row.end() / size) {
+                let (r, c) = (fr * size, fc * size);
+                let covered = (r..r + size).all(|r| {
+                    let row = board.rows.get(r);
+                    row.is_some_and(|row| row.start() <= c && c + size - 1 <= row.end())
+                });
+                if !covered {
+                    bail!(
+                        "the board doesn't split into {}x{} faces (around row {}, column {})",
+                        size,
+                        size,
+                        r + 1,
+                        c + 1
+                    );
+                }
+                faces.push((fr, fc));
+            }
+        }
+        if faces.len() != 6 {
+            bail!("the board has {} faces, but a cube has 6", faces.len());
+        }
+
+        // Fold the faces up around the first one, one edge of the net at a time.
+        let mut orientations: Vec<Option<Orientation>> = vec![None; 6];
+        orientations[0] = Some(Orientation {
+            normal: Point3::new(0, 0, -1),
+            east: Point3::new(1, 0, 0),
+            south: Point3::new(0, 1, 0),
+        });
+        let mut queue = vec![0];
+        while let Some(f) = queue.pop() {
+            let (fr, fc) = faces[f];
+            let orientation = orientations[f].unwrap();
+            for h in Heading::ALL {
+                let d = h.delta::<i32>();
+                let next = (fr as i32 + d.y, fc as i32 + d.x);
+                let Some(g) = faces
+                    .iter()
+                    .position(|&(r, c)| (r as i32, c as i32) == next)
+                else {
+                    continue;
+                };
+                if orientations[g].is_none() {
+                    orientations[g] = Some(orientation.roll(h));
+                    queue.push(g);
+                }
+            }
+        }
+
+        let Some(orientations): Option<Vec<Orientation>> = orientations.into_iter().collect()
+        else {
+            bail!("the faces aren't all connected to each other");
+        };
+        for (f, a) in orientations.iter().enumerate() {
+            if let Some(g) = (0..f).find(|&g| orientations[g].normal == a.normal) {
+                let corner = |(r, c): (usize, usize)| (r * size + 1, c * size + 1);
+                bail!(
+                    "the faces at (row, column) {:?} and {:?} fold onto the same side of the cube",
+                    corner(faces[g]),
+                    corner(faces[f]),
+                );
+            }
+        }
+
+        // Walking off an edge leads onto the face on that side of the cube, heading away from the
+        // face you left.
+        let edges = orientations
+            .iter()
+            .map(|a| {
+                Heading::ALL.map(|h| {
+                    let b = orientations
+                        .iter()
+                        .position(|b| b.normal == a.dir(h))
+                        .unwrap();
+                    let hh = Heading::ALL
+                        .into_iter()
+                        .find(|&hh| orientations[b].dir(hh) == -a.normal)
+                        .unwrap();
+                    (b, hh)
+                })
+            })
+            .collect();
+
+        Ok(Self { size, faces, edges })
+    }
+
+    /// Walks off the edge of the current face, returning where that leads and what's there.
+    fn faceroll(
+        &self,
+        board: &Board,
+        r: usize,
+        c: usize,
+        h: Heading,
+    ) -> (usize, usize, Heading, Tile) {
+        let n = self.size;
+        let f = self
+            .faces
+            .iter()
+            .position(|&face| face == (r / n, c / n))
+            .expect("on a face");
+        let (b, hh) = self.edges[f][index(h)];
+
+        // The edge you cross is the same edge of the cube from either side, so counting along it
+        // from the left on the way out is the same as counting from the right on the way in.
+        let k = offset(h, r % n, c % n, n);
+        let (i, j) = edge_tile(hh.flip(), n - 1 - k, n);
+
+        let (fr, fc) = self.faces[b];
+        let (rr, cc) = (fr * n + i, fc * n + j);
+        let t = board.rows[rr].at(cc).expect("folded onto a face");
+        (rr, cc, hh, t)
+    }
+}
+
+/// How far along the edge on the `h` side of the face the tile is, counting from the left (when
+/// facing that way).
+fn offset(h: Heading, i: usize, j: usize, n: usize) -> usize {
+    match h {
+        Heading::North => j,
+        Heading::East => i,
+        Heading::South => n - 1 - j,
+        Heading::West => n - 1 - i,
+    }
+}
+
+/// The tile `k` along the edge on the `h` side of the face, counting from the left.
+fn edge_tile(h: Heading, k: usize, n: usize) -> (usize, usize) {
+    match h {
+        Heading::North => (0, k),
+        Heading::East => (k, n - 1),
+        Heading::South => (n - 1, n - 1 - k),
+        Heading::West => (n - 1 - k, 0),
+    }
+}
+
+aoc::example_tests! {
+    Day22,
+    part1 = "6032",
+    part2 = "5031",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The net from the real input, with every tile open.
+    fn real_net() -> Board {
+        let rows: Vec<String> = (0..200)
+            .map(|r| match r {
+                0..=49 => format!("{}{}", " ".repeat(50), ".".repeat(100)),
+                50..=99 => format!("{}{}", " ".repeat(50), ".".repeat(50)),
+                100..=149 => ".".repeat(100),
+                _ => ".".repeat(50),
+            })
+            .collect();
+        Board::parse(&rows.join("\n")).unwrap()
+    }
+
+    /// The edges that used to be hard-coded for the real input's net.
+    fn hard_coded(r: usize, c: usize, h: Heading) -> Option<(usize, usize, Heading)> {
+        use Heading::*;
+
+        Some(match (r, c, h) {
+            (0..=49, 149, East) => (149 - r, 99, West),
+            (0..=49, 50, West) => (149 - r, 0, East),
+            (100..=149, 0, West) => (149 - r, 50, East),
+            (50..=99, 50, West) => (100, r - 50, South),
+            (100, 0..=49, North) => (c + 50, 50, East),
+            (0, 50..=99, North) => (c + 100, 0, East),
+            (199, 0..=49, South) => (0, c + 100, South),
+            (150..=199, 49, East) => (149, r - 100, North),
+            (0, 100..=149, North) => (199, c - 100, North),
+            (149, 50..=99, South) => (c + 100, 49, West),
+            (150..=199, 0, West) => (0, r - 100, South),
+            (50..=99, 99, East) => (49, r + 50, North),
+            (100..=149, 99, East) => (149 - r, 149, West),
+            (49, 100..=149, South) => (c - 50, 99, West),
+            _ => return None,
+        })
+    }
+
+    #[test]
+    fn folds_like_the_hard_coded_net() {
+        let board = real_net();
+        let cube = Cube::fold(&board).unwrap();
+        assert_eq!(cube.size, 50);
+
+        let mut edges = 0;
+        for (r, row) in board.rows.iter().enumerate() {
+            for c in row.start()..=row.end() {
+                for h in Heading::ALL {
+                    let Some(expected) = hard_coded(r, c, h) else {
+                        continue;
+                    };
+                    let (rr, cc, hh, _) = cube.faceroll(&board, r, c, h);
+                    assert_eq!((rr, cc, hh), expected, "from {:?}", (r, c, h));
+                    edges += 1;
+                }
+            }
+        }
+        // 14 edges of the net, 50 tiles each, with the corners counted from both sides.
+        assert_eq!(edges, 14 * 50);
+    }
+
+    #[test]
+    fn not_a_cube() {
+        let err = |text: &str| {
+            Cube::fold(&Board::parse(text).unwrap())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err("...."),
+            "the board has 4 tiles, which can't be six square faces"
+        );
+        assert_eq!(
+            err("......"),
+            "the faces at (row, column) (1, 1) and (1, 5) fold onto the same side of the cube"
+        );
+        assert_eq!(
+            err(" ..\n ..\n........\n........\n..\n.."),
+            "the board doesn't split into 2x2 faces (around row 1, column 1)"
+        );
+    }
+
+    #[test]
+    fn awkward_boards() {
+        // Start from the first open tile, even if the row starts with a wall.
+        let (board, moves) = parse("#..\n\n1R1").unwrap();
+        assert_eq!(board.start().unwrap(), (0, 1));
+        assert_eq!(
+            part1(board.clone(), moves.clone()).unwrap(),
+            1000 + 4 * 3 + 1
+        );
+        assert_eq!(
+            part2(board, moves).unwrap_err().to_string(),
+            "the board has 3 tiles, which can't be six square faces"
+        );
+
+        let err = |text: &str| parse(text).unwrap_err().to_string();
+        assert_eq!(
+            err("###\n\n1"),
+            "there's no open tile in the top row to start on"
+        );
+        assert_eq!(
+            err("...\n.\n...\n\n1"),
+            "column 2 has a gap in it before row 3, which can't be walked around"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use aoc::{grid::RC, BitGrid, Input, Solution};
+
+pub struct Day23 {
+    grove: Grove,
+}
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let grove = Grove::parse(input)?;
+        Ok(Self { grove })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        Ok(part1(self.grove.clone()))
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        Ok(part2(self.grove.clone()))
+    }
+}
+
+type Delta = (isize, isize);
+
+#[rustfmt::skip]
+const NEIGHBORS: [Delta; 8] = [
+    (-1, -1), (-1,  0), (-1,  1),
+    ( 0, -1),           ( 0,  1),
+    ( 1, -1), ( 1,  0), ( 1,  1),
+];
+
+const NORTH: [Delta; 3] = [(-1, -1), (-1, 0), (-1, 1)];
+const SOUTH: [Delta; 3] = [(1, -1), (1, 0), (1, 1)];
+const WEST: [Delta; 3] = [(-1, -1), (0, -1), (1, -1)];
+const EAST: [Delta; 3] = [(-1, 1), (0, 1), (1, 1)];
+
+/// The grove spreads out without limit, so the grid keeps an empty border around the elves and
+/// grows whenever one of them reaches it. `origin` is wherever the top-left of the input ended up.
+#[derive(Debug, Clone)]
+struct Grove {
+    elves: BitGrid,
+    origin: RC,
+}
+
+impl Grove {
+    const MARGIN: usize = 8;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let elves = BitGrid::parse(input, |fill| match fill {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            other => bail!("unexpected {:?}", other),
+        })?;
+
+        Ok(Self {
+            elves: elves.padded(Self::MARGIN),
+            origin: (Self::MARGIN, Self::MARGIN),
+        })
+    }
+
+    /// Makes sure no elf is on the edge of the grid, so every step they look at is on it.
+    fn make_room(&mut self) {
+        let (height, width) = (self.elves.height(), self.elves.width());
+        let crowded = self
+            .elves
+            .iter()
+            .any(|(r, c)| r == 0 || c == 0 || r == height - 1 || c == width - 1);
+
+        if crowded {
+            self.elves = self.elves.padded(Self::MARGIN);
+            self.origin = (self.origin.0 + Self::MARGIN, self.origin.1 + Self::MARGIN);
+        }
+    }
+
+    fn step((r, c): RC, (dr, dc): Delta) -> RC {
+        // Never underflows: nobody stands on the edge (see `make_room`).
+        (r.wrapping_add_signed(dr), c.wrapping_add_signed(dc))
+    }
+
+    fn propose(&self, from: RC, round: usize) -> Option<RC> {
+        if !self.has_elves(from, &NEIGHBORS) {
+            return None;
+        }
+
+        let mut props: [(Delta, &[Delta]); 4] = [
+            ((-1, 0), &NORTH),
+            ((1, 0), &SOUTH),
+            ((0, -1), &WEST),
+            ((0, 1), &EAST),
+        ];
+
+        props.rotate_left(round % 4);
+
+        for (dir, neighbors) in props {
+            if !self.has_elves(from, neighbors) {
+                return Some(Self::step(from, dir));
+            }
+        }
+
+        None
+    }
+
+    fn has_elf(&self, loc: RC) -> bool {
+        self.elves.get(loc)
+    }
+
+    fn has_elves(&self, loc: RC, deltas: &[Delta]) -> bool {
+        deltas
+            .iter()
+            .any(|&delta| self.has_elf(Self::step(loc, delta)))
+    }
+
+    fn apply(&self, moves: Vec<Move>) -> Self {
+        let mut elves = self.elves.clone();
+
+        for Move { from, to } in moves {
+            let was_present = elves.remove(from);
+            assert!(was_present);
+
+            let was_empty = elves.insert(to);
+            assert!(was_empty);
+        }
+
+        Self {
+            elves,
+            origin: self.origin,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Move {
+    from: RC,
+    to: RC,
+}
+
+fn part1(mut grove: Grove) -> usize {
+    for round in 0..10 {
+        (grove, _) = simulate(grove, round);
+    }
+    grove.empty_tiles()
+}
+
+fn part2(mut grove: Grove) -> usize {
+    for round in 0.. {
+        let done;
+        (grove, done) = simulate(grove, round);
+
+        if done {
+            return round + 1;
+        }
+    }
+    unreachable!()
+}
+
+fn simulate(mut grove: Grove, round: usize) -> (Grove, bool) {
+    grove.make_room();
+
+    let mut proposals: HashMap<RC, Vec<RC>> = HashMap::new();
+    for src in grove.elves.iter() {
+        let Some(dest) = grove.propose(src, round) else {
+            continue;
+        };
+        proposals
+            .entry(dest)
+            .and_modify(|v| v.push(src))
+            .or_insert_with(|| vec![src]);
+    }
+
+    let mut moves: Vec<Move> = vec![];
+    for (dest, sources) in proposals {
+        if sources.len() != 1 {
+            continue;
+        }
+
+        let src = sources[0];
+        moves.push(Move {
+            from: src,
+            to: dest,
+        });
+    }
+
+    let done = moves.is_empty();
+
+    (grove.apply(moves), done)
+}
+
+impl Grove {
+    fn empty_tiles(&self) -> usize {
+        let (lo, hi) = self.bounds();
+
+        let rows = 1 + hi.0 - lo.0;
+        let cols = 1 + hi.1 - lo.1;
+
+        (rows * cols) - self.elves.len()
+    }
+
+    fn bounds(&self) -> (RC, RC) {
+        let far = (usize::MAX, usize::MAX);
+        self.elves.iter().fold((far, (0, 0)), |(lo, hi), (r, c)| {
+            ((lo.0.min(r), lo.1.min(c)), (hi.0.max(r), hi.1.max(c)))
+        })
+    }
+}
+
+impl std::fmt::Display for Grove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (lo, hi) = self.bounds();
+
+        for r in lo.0..=hi.0 {
+            for c in lo.1..=hi.1 {
+                let is_origin = (r, c) == self.origin;
+                let is_elf = self.has_elf((r, c));
+
+                let chr = match (is_origin, is_elf) {
+                    (true, true) => 'X',
+                    (true, false) => 'O',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                };
+
+                write!(f, "{}", chr)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+aoc::example_tests! {
+    Day23,
+    part1 = "110",
+    part2 = "20",
+}
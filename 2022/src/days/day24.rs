@@ -0,0 +1,348 @@
+use std::hash::{Hash, Hasher};
+
+use anyhow::{bail, Context};
+use aoc::{
+    grid::{Grid, RC},
+    search, Heading, Input, Point2, Solution,
+};
+
+pub struct Day24 {
+    valley: Valley,
+}
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let valley = Valley::parse(input)?;
+        Ok(Self { valley })
+    }
+
+    fn part1(&self) -> anyhow::Result<usize> {
+        part1(self.valley.clone())
+    }
+
+    fn part2(&self) -> anyhow::Result<usize> {
+        let t_goal = part1(self.valley.clone())?;
+        part2(self.valley.clone(), t_goal)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Open,
+    Wall,
+    Blizzard(Heading),
+}
+
+/// Column and row, like the grid: `y` grows toward the bottom of the valley.
+type Pos = Point2<i64>;
+
+fn pos((r, c): RC) -> Pos {
+    Pos::new(c as i64, r as i64)
+}
+
+fn rc(p: Pos) -> Option<RC> {
+    Some((p.y.try_into().ok()?, p.x.try_into().ok()?))
+}
+
+#[derive(Debug, Clone)]
+struct Valley {
+    map: Grid<Tile>,
+    /// Where each blizzard started out, and which way it's blowing.
+    blizzards: Vec<(Pos, Heading)>,
+    start: Pos,
+    goal: Pos,
+    height: i64,
+    width: i64,
+}
+
+impl Valley {
+    fn parse(text: &str) -> anyhow::Result<Self> {
+        let map = Grid::parse(text, |fill| match fill {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            '^' => Ok(Tile::Blizzard(Heading::North)),
+            '>' => Ok(Tile::Blizzard(Heading::East)),
+            'v' => Ok(Tile::Blizzard(Heading::South)),
+            '<' => Ok(Tile::Blizzard(Heading::West)),
+            other => bail!("unknown tile: {:?}", other),
+        })?;
+
+        let (height, width) = (map.height(), map.width());
+        if height < 3 || width < 3 {
+            bail!(
+                "the valley needs walls all the way around, but it's only {}x{}",
+                width,
+                height
+            );
+        }
+
+        let blizzards = map
+            .iter()
+            .filter_map(|(rc, &tile)| match tile {
+                Tile::Blizzard(dir) => Some((pos(rc), dir)),
+                _ => None,
+            })
+            .collect();
+
+        let gap = |r: usize| {
+            let c = (0..width).rev().find(|&c| map[(r, c)] == Tile::Open);
+            pos((r, c.unwrap_or(0)))
+        };
+        let start = gap(0);
+        let goal = gap(height - 1);
+
+        let valley = Self {
+            map,
+            blizzards,
+            start,
+            goal,
+            height: height as i64,
+            width: width as i64,
+        };
+
+        valley.check()?;
+        Ok(valley)
+    }
+
+    fn check(&self) -> anyhow::Result<()> {
+        // Assumptions:
+        if self.start != Pos::new(1, 0) {
+            bail!("expected the start in the top left corner");
+        }
+        if self.goal != Pos::new(self.width - 2, self.height - 1) {
+            bail!("expected the goal in the bottom right corner");
+        }
+
+        for &(p, _) in &self.blizzards {
+            let inside = (1..self.width - 1).contains(&p.x) && (1..self.height - 1).contains(&p.y);
+            if !inside {
+                bail!(
+                    "there's a blizzard in the wall at line {}, column {}",
+                    p.y + 1,
+                    p.x + 1
+                );
+            }
+        }
+
+        for (rc, &tile) in self.map.iter() {
+            let p = pos(rc);
+            let border = p.x == 0 || p.y == 0 || p.x == self.width - 1 || p.y == self.height - 1;
+            if border && tile != Tile::Wall && p != self.start && p != self.goal {
+                bail!(
+                    "there's a gap in the wall at line {}, column {}",
+                    p.y + 1,
+                    p.x + 1
+                );
+            }
+        }
+
+        if !self.safe(0, self.start) || !self.safe(0, self.goal) {
+            bail!("there's a blizzard on the start or the goal");
+        }
+
+        Ok(())
+    }
+
+    fn safe(&self, minutes: usize, loc: Pos) -> bool {
+        match rc(loc).and_then(|rc| self.map.get(rc)) {
+            // Out of bounds!
+            None => return false,
+            // Can't stand here.
+            Some(Tile::Wall) => return false,
+            Some(_) => {}
+        }
+
+        // TODO: is it faster to just slice the row and column out?
+        for &(start, dir) in &self.blizzards {
+            if self.blizzard_pos(minutes, start, dir) == loc {
+                // Oops!
+                return false;
+            }
+        }
+
+        // No danger... for now!
+        true
+    }
+
+    /// How many minutes until every blizzard is back where it started.
+    fn period(&self) -> usize {
+        fn gcd(a: i64, b: i64) -> i64 {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        let inner = self.inner();
+        (inner.y / gcd(inner.y, inner.x) * inner.x) as usize
+    }
+
+    /// The size of the valley inside the walls (two of them, always), where the blizzards blow.
+    fn inner(&self) -> Pos {
+        Pos::new(self.width - 2, self.height - 2)
+    }
+
+    fn blizzard_pos(&self, minutes: usize, start: Pos, dir: Heading) -> Pos {
+        // Take off the wall to get into a wall-less space, and then add it back to get back to
+        // normal.
+        let wall = Pos::new(1, 1);
+        let inner = self.inner();
+
+        let p = start - wall + dir.delta() * minutes as i64;
+        Pos::new(p.x.rem_euclid(inner.x), p.y.rem_euclid(inner.y)) + wall
+    }
+}
+
+/// Where the expedition could be, and when.
+///
+/// The blizzards are back where they started every `period` minutes, so two states at the same
+/// spot a whole number of periods apart are the same as far as the search is concerned. Comparing
+/// them that way keeps the search finite when the goal can't be reached.
+#[derive(Debug, Copy, Clone)]
+struct State {
+    loc: Pos,
+    minutes: usize,
+    period: usize,
+}
+
+impl State {
+    fn key(&self) -> (Pos, usize) {
+        (self.loc, self.minutes % self.period)
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl State {
+    /// Waiting, or one step any way. Whether it's safe there is up to the valley.
+    fn destinations(&self) -> Vec<Pos> {
+        let steps = Heading::ALL.map(|dir| self.loc + dir.delta());
+        std::iter::once(self.loc).chain(steps).collect()
+    }
+}
+
+fn part1(valley: Valley) -> anyhow::Result<usize> {
+    let start = valley.start;
+    let goal = valley.goal;
+    search(valley, 0, start, goal)
+}
+
+fn part2(valley: Valley, t_goal: usize) -> anyhow::Result<usize> {
+    let start = valley.start;
+    let goal = valley.goal;
+    let t_start = search(valley.clone(), t_goal, goal, start)?;
+
+    search(valley, t_start, start, goal)
+}
+
+fn search(
+    valley: Valley,
+    start_minutes: usize,
+    start_loc: Pos,
+    goal: Pos,
+) -> anyhow::Result<usize> {
+    let heuristic = move |state: &State| -> usize { goal.manhattan(state.loc) as usize };
+
+    let successors = |state: &State| -> Vec<(State, usize)> {
+        let minutes = state.minutes + 1;
+
+        state
+            .destinations()
+            .into_iter()
+            .filter(|&loc| valley.safe(minutes, loc))
+            .map(|loc| {
+                let period = state.period;
+                (
+                    State {
+                        loc,
+                        minutes,
+                        period,
+                    },
+                    1,
+                )
+            })
+            .collect()
+    };
+
+    let start = State {
+        minutes: start_minutes,
+        loc: start_loc,
+        period: valley.period(),
+    };
+
+    let path = search::astar([start], successors, heuristic, |state| state.loc == goal)
+        .context("no way through the blizzards 🥶")?;
+    Ok(path.goal().minutes)
+}
+
+aoc::example_tests! {
+    Day24,
+    part1 = "18",
+    part2 = "54",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degenerate_valleys() {
+        let err = |text| Valley::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            err("#.#\n"),
+            "the valley needs walls all the way around, but it's only 3x1"
+        );
+        assert_eq!(
+            err("#.#\n#.#\n"),
+            "the valley needs walls all the way around, but it's only 3x2"
+        );
+        assert_eq!(
+            err(""),
+            "the valley needs walls all the way around, but it's only 0x1"
+        );
+        assert_eq!(
+            err("#.###\n#...#\n>...#\n#...#\n###.#\n"),
+            "there's a blizzard in the wall at line 3, column 1"
+        );
+        assert_eq!(
+            err("#.###\n....#\n###.#\n"),
+            "there's a gap in the wall at line 2, column 1"
+        );
+        assert_eq!(
+            err("#.###\n#..\n###.#\n"),
+            "line 2: expected 5 cells, got 3\n  | #.."
+        );
+        assert_eq!(
+            err("#.#v#\n#...#\n###.#\n"),
+            "there's a blizzard in the wall at line 1, column 4"
+        );
+    }
+
+    #[test]
+    fn walled_off() {
+        let valley = Valley::parse("#.####\n#.#..#\n####.#\n").unwrap();
+        assert_eq!(valley.period(), 4);
+        assert_eq!(
+            part1(valley).unwrap_err().to_string(),
+            "no way through the blizzards 🥶"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use anyhow::{bail, Context};
+use aoc::{Input, Solution};
+
+pub struct Day25 {
+    numbers: Vec<i64>,
+}
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Answer1 = String;
+    type Answer2 = &'static str;
+
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let numbers = aoc::error::lines(input, parse_snafu)?;
+        Ok(Self { numbers })
+    }
+
+    fn part1(&self) -> anyhow::Result<String> {
+        part1(&self.numbers)
+    }
+
+    fn part2(&self) -> anyhow::Result<&'static str> {
+        // There's no puzzle for this one. Just start the blender!
+        Ok("Merry Christmas!")
+    }
+}
+
+fn parse_snafu(line: &str) -> anyhow::Result<i64> {
+    let mut num: i64 = 0;
+    for (c, digit) in line.chars().enumerate() {
+        let val = match digit {
+            '=' => -2,
+            '-' => -1,
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            other => {
+                let err = format!("not a SNAFU digit: {:?}", other);
+                return Err(aoc::Error::new(err).with_column(c + 1).into());
+            }
+        };
+
+        num = num
+            .checked_mul(5)
+            .and_then(|n| n.checked_add(val))
+            .context("too big for an i64")?;
+    }
+    Ok(num)
+}
+
+fn part1(numbers: &[i64]) -> anyhow::Result<String> {
+    let sum: i64 = numbers.iter().sum();
+    if sum <= 0 {
+        bail!(
+            "the fuel requirements add up to {}, which isn't positive",
+            sum
+        );
+    }
+    Ok(snafu(sum))
+}
+
+fn snafu(mut n: i64) -> String {
+    assert!(n > 0);
+
+    let mut digits: VecDeque<i64> = VecDeque::new();
+
+    while n > 0 {
+        match n % 5 {
+            3 => digits.push_front(-2),
+            4 => digits.push_front(-1),
+            0 => digits.push_front(0),
+            1 => digits.push_front(1),
+            2 => digits.push_front(2),
+            _ => unreachable!(),
+        };
+        n -= digits[0];
+        n /= 5;
+    }
+
+    digits
+        .iter()
+        .map(|digit| match digit {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+aoc::example_tests! {
+    Day25,
+    part1 = "2=-1=0",
+    part2 = "Merry Christmas!",
+}
@@ -0,0 +1,207 @@
+//! Errors that point at the part of the puzzle input that caused them.
+
+use std::fmt::Display;
+
+use anyhow::bail;
+
+use crate::scan::Mismatch;
+
+/// Something wrong with a puzzle input: which day, which line (and column), and what that line said.
+///
+/// Everything but the message is optional, and filled in by whoever knows it. A line-by-line parser
+/// knows the line, and [`Day::solve`](crate::Day::solve) knows the day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: Option<u32>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            snippet: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// The error from parsing one line of the input. Lines start at 1.
+    ///
+    /// If the line didn't match a [`scan!`](crate::scan!) pattern, this picks up the column too.
+    pub fn at_line(line: usize, snippet: &str, err: anyhow::Error) -> Self {
+        let mut error = Self::from(err);
+        error.line.get_or_insert(line);
+        error.snippet.get_or_insert_with(|| snippet.to_string());
+        error
+    }
+
+    /// For errors about a single character of the line. Columns start at 1.
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// For an error from parsing a piece of the input that started after `lines` other lines:
+    /// moves the line number down to where it really is.
+    pub fn after(mut self, lines: usize) -> Self {
+        if let Some(line) = &mut self.line {
+            *line += lines;
+        }
+        self
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<Error>() {
+            Ok(error) => return error,
+            Err(err) => err,
+        };
+
+        match err.downcast::<Mismatch>() {
+            Ok(m) => Self {
+                column: Some(m.column),
+                ..Self::new(m.message)
+            },
+            Err(err) => Self::new(format!("{:#}", err)),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location: Vec<String> = [
+            self.day.map(|d| format!("Day {}", d)),
+            self.line.map(|l| format!("line {}", l)),
+            self.column.map(|c| format!("column {}", c)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n  | {}", snippet)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {:>width$}", "^", width = column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Runs `parse` on every line of the text, pointing at the line that went wrong (if any).
+pub fn lines<T>(
+    text: &str,
+    mut parse: impl FnMut(&str) -> anyhow::Result<T>,
+) -> anyhow::Result<Vec<T>> {
+    crate::lines(text)
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| Error::at_line(i + 1, line, err).into()))
+        .collect()
+}
+
+/// Runs `parse` on line `i` (counting from 0) of some input that's already been split up, for
+/// inputs where each line means something different.
+pub fn line<T>(
+    lines: &[&str],
+    i: usize,
+    parse: impl FnOnce(&str) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let Some(line) = lines.get(i) else {
+        bail!("expected at least {} lines, got {}", i + 1, lines.len());
+    };
+    parse(line).map_err(|err| Error::at_line(i + 1, line, err).into())
+}
+
+/// Runs `parse` on every blank-line-separated block of the text. Errors that don't say which line
+/// of the block they came from point at the start of it.
+pub fn blocks<T>(
+    text: &str,
+    mut parse: impl FnMut(&str) -> anyhow::Result<T>,
+) -> anyhow::Result<Vec<T>> {
+    let mut start = 0;
+    crate::blocks(text.trim_end())
+        .map(|block| {
+            let before = start;
+            start += block.lines().count() + 1;
+
+            parse(block).map_err(|err| {
+                let first = block.lines().next().unwrap_or_default();
+                Error::at_line(1, first, err).after(before).into()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_line() {
+        let text = "x=1\nx=2\nx=?\n";
+        let err = lines(text, |line| Ok(crate::scan!(line, "x={}", u8)?)).unwrap_err();
+        let err = Error::from(err).with_day(3);
+        assert_eq!(
+            err.to_string(),
+            [
+                r#"Day 3, line 3, column 3: can't parse "?" as u8: invalid digit found in string"#,
+                "  | x=?",
+                "  |   ^",
+            ]
+            .join("\n")
+        );
+
+        let ok = lines(text.replace('?', "3").as_str(), |line| {
+            Ok(crate::scan!(line, "x={}", u8)?)
+        });
+        assert_eq!(ok.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn any_error_works() {
+        let err = lines("a\nb", |line| match line {
+            "a" => Ok(()),
+            other => bail!("unexpected {:?}", other),
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "line 2: unexpected \"b\"\n  | b");
+
+        assert_eq!(Error::new("oops").with_day(1).to_string(), "Day 1: oops");
+    }
+
+    #[test]
+    fn blocks_count_from_the_top() {
+        let text = "a\nb\n\nc\nd\n\ne\n";
+        let err = blocks(text, |block| {
+            lines(block, |line| match line {
+                "d" => bail!("not d"),
+                _ => Ok(()),
+            })
+        });
+        assert_eq!(err.unwrap_err().to_string(), "line 5: not d\n  | d");
+
+        let err = blocks(text.replace('d', "x").as_str(), |block| match block {
+            "e" => bail!("not e"),
+            _ => Ok(()),
+        });
+        assert_eq!(err.unwrap_err().to_string(), "line 7: not e\n  | e");
+    }
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use crate::{
+    input::Source,
+    solution::{Part, Solution},
+};
+
+/// Generates a `#[test]` for each part of a day, checking its answer for `input/dayNN-ex.txt` in the
+/// calling crate.
+///
+/// ```ignore
+/// aoc::example_tests! {
+///     Day05,
+///     part1 = "CMZ",
+///     part2 = "MCD",
+/// }
+/// ```
+///
+/// Attributes go through to the test, so a known-broken part can be `#[ignore]`d with a reason.
+#[macro_export]
+macro_rules! example_tests {
+    (
+        $solution:ty,
+        $(#[$attr1:meta])* part1 = $part1:expr,
+        $(#[$attr2:meta])* part2 = $part2:expr $(,)?
+    ) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+            #[test]
+            $(#[$attr1])*
+            fn part1() {
+                $crate::examples::check::<$solution>(INPUT_DIR, $crate::Part::One, $part1);
+            }
+
+            #[test]
+            $(#[$attr2])*
+            fn part2() {
+                $crate::examples::check::<$solution>(INPUT_DIR, $crate::Part::Two, $part2);
+            }
+        }
+    };
+}
+
+/// The body of each test from [`example_tests!`].
+///
+/// The example inputs are checked in, so a missing (or still empty) file fails the test. A day
+/// that doesn't have its example yet should `#[ignore]` its tests instead.
+pub fn check<S: Solution>(input_dir: &str, part: Part, expected: &str) {
+    let dir = Path::new(input_dir);
+    let input = Source::Example.read(dir, S::DAY).unwrap();
+    assert!(
+        !input.trim().is_empty(),
+        "day {} part {}: the example input is empty",
+        S::DAY,
+        part
+    );
+
+    let solution = S::parse(&input).unwrap();
+    let got = match part {
+        Part::One => solution.part1().unwrap().to_string(),
+        Part::Two => solution.part2().unwrap().to_string(),
+    };
+
+    assert_eq!(got.trim(), expected.trim());
+}
@@ -0,0 +1,237 @@
+//! Weighted directed graphs with a fixed set of nodes, for all-pairs questions.
+//!
+//! For searching a graph that's too big (or too implicit) to write down, see [`crate::search`].
+
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+#[derive(Debug, Clone)]
+pub struct Graph<V, W> {
+    nodes: Vec<V>,
+    index: HashMap<V, usize>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<V, W> Default for Graph<V, W> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<V, W> Graph<V, W>
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node if it's new. Either way, returns its index.
+    pub fn add_node(&mut self, v: V) -> usize {
+        if let Some(&i) = self.index.get(&v) {
+            return i;
+        }
+
+        let i = self.nodes.len();
+        self.nodes.push(v.clone());
+        self.index.insert(v, i);
+        self.edges.push(Vec::new());
+        i
+    }
+
+    /// Adds a one-way edge (and its nodes, if they're new).
+    pub fn add_edge(&mut self, from: V, to: V, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn index(&self, v: &V) -> Option<usize> {
+        self.index.get(v).copied()
+    }
+
+    pub fn node(&self, i: usize) -> &V {
+        &self.nodes[i]
+    }
+
+    pub fn nodes(&self) -> &[V] {
+        &self.nodes
+    }
+
+    /// The edges leaving `v`, with their weights.
+    pub fn neighbors(&self, v: &V) -> impl Iterator<Item = (&V, W)> {
+        let edges = match self.index(v) {
+            Some(i) => &self.edges[i][..],
+            None => &[],
+        };
+        edges.iter().map(|&(j, w)| (&self.nodes[j], w))
+    }
+
+    /// Shortest distances between every pair of nodes, by index.
+    pub fn shortest_paths(&self) -> ShortestPaths<W> {
+        let edges = self
+            .edges
+            .iter()
+            .enumerate()
+            .flat_map(|(i, out)| out.iter().map(move |&(j, w)| (i, j, w)));
+        floyd_warshall(self.len(), edges)
+    }
+
+    /// The nodes along the shortest path from `from` to `to`, including both ends.
+    pub fn path(&self, paths: &ShortestPaths<W>, from: &V, to: &V) -> Option<Vec<V>> {
+        let route = paths.path(self.index(from)?, self.index(to)?)?;
+        Some(route.into_iter().map(|i| self.nodes[i].clone()).collect())
+    }
+
+    /// Collapses the graph down to only the nodes worth stopping at, with an edge between each pair
+    /// weighted by the shortest distance between them in the full graph.
+    pub fn compress(&self, mut keep: impl FnMut(&V) -> bool) -> Self {
+        let paths = self.shortest_paths();
+        let kept: Vec<usize> = (0..self.len()).filter(|&i| keep(&self.nodes[i])).collect();
+
+        let mut graph = Self::new();
+        for &i in &kept {
+            graph.add_node(self.nodes[i].clone());
+        }
+        for &i in &kept {
+            for &j in &kept {
+                if i == j {
+                    continue;
+                }
+                if let Some(d) = paths.dist(i, j) {
+                    graph.add_edge(self.nodes[i].clone(), self.nodes[j].clone(), d);
+                }
+            }
+        }
+        graph
+    }
+}
+
+/// The output of [`floyd_warshall`]: distances and routes between every pair of node indexes.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<W> {
+    n: usize,
+    dist: Vec<Option<W>>,
+    next: Vec<Option<usize>>,
+}
+
+impl<W: Copy> ShortestPaths<W> {
+    /// `None` if `j` can't be reached from `i`.
+    pub fn dist(&self, i: usize, j: usize) -> Option<W> {
+        self.dist[i * self.n + j]
+    }
+
+    /// The indexes along the shortest path from `i` to `j`, including both ends.
+    pub fn path(&self, mut i: usize, j: usize) -> Option<Vec<usize>> {
+        let mut path = vec![i];
+        while i != j {
+            i = self.next[i * self.n + j]?;
+            path.push(i);
+        }
+        Some(path)
+    }
+}
+
+/// All-pairs shortest paths for nodes `0..n` and one-way weighted edges between them.
+pub fn floyd_warshall<W>(
+    n: usize,
+    edges: impl IntoIterator<Item = (usize, usize, W)>,
+) -> ShortestPaths<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let mut dist: Vec<Option<W>> = vec![None; n * n];
+    let mut next: Vec<Option<usize>> = vec![None; n * n];
+
+    for (i, j, w) in edges {
+        if dist[i * n + j].is_none_or(|d| w < d) {
+            dist[i * n + j] = Some(w);
+            next[i * n + j] = Some(j);
+        }
+    }
+    for v in 0..n {
+        dist[v * n + v] = Some(W::default());
+        next[v * n + v] = Some(v);
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(d_ik) = dist[i * n + k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(d_kj) = dist[k * n + j] else {
+                    continue;
+                };
+
+                let d_ikj = d_ik + d_kj;
+                if dist[i * n + j].is_none_or(|d_ij| d_ikj < d_ij) {
+                    dist[i * n + j] = Some(d_ikj);
+                    next[i * n + j] = next[i * n + k];
+                }
+            }
+        }
+    }
+
+    ShortestPaths { n, dist, next }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A -> B -> C -> D is cheaper than A -> D directly, and E is off on its own.
+    fn graph() -> Graph<char, u32> {
+        let mut g = Graph::new();
+        g.add_edge('A', 'B', 1);
+        g.add_edge('B', 'C', 2);
+        g.add_edge('C', 'D', 3);
+        g.add_edge('A', 'D', 10);
+        g.add_node('E');
+        g
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let g = graph();
+        let paths = g.shortest_paths();
+        let (a, d, e) = (
+            g.index(&'A').unwrap(),
+            g.index(&'D').unwrap(),
+            g.index(&'E').unwrap(),
+        );
+
+        assert_eq!(paths.dist(a, d), Some(6));
+        assert_eq!(paths.dist(d, a), None);
+        assert_eq!(paths.dist(a, e), None);
+        assert_eq!(paths.dist(e, e), Some(0));
+
+        assert_eq!(g.path(&paths, &'A', &'D'), Some(vec!['A', 'B', 'C', 'D']));
+        assert_eq!(g.path(&paths, &'D', &'A'), None);
+        assert_eq!(g.path(&paths, &'C', &'C'), Some(vec!['C']));
+    }
+
+    #[test]
+    fn compress() {
+        let g = graph().compress(|&v| v == 'A' || v == 'C' || v == 'D');
+        assert_eq!(g.nodes(), &['A', 'C', 'D']);
+
+        let mut from_a: Vec<(char, u32)> = g.neighbors(&'A').map(|(&v, w)| (v, w)).collect();
+        from_a.sort();
+        assert_eq!(from_a, vec![('C', 3), ('D', 6)]);
+        assert_eq!(g.neighbors(&'D').count(), 0);
+        assert_eq!(g.neighbors(&'B').count(), 0);
+    }
+}
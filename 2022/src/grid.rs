@@ -0,0 +1,285 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::anyhow;
+
+use crate::Error;
+
+/// A (row, column) position in a [`Grid`], with the origin at the top left.
+pub type RC = (usize, usize);
+
+/// Steps to the four orthogonal neighbors: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Steps to all eight neighbors, clockwise from up.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A dense rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            height,
+            width,
+            cells: vec![fill; height * width],
+        }
+    }
+
+    /// Parses a character map, one row per line. Every row has to be the same length.
+    pub fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        let mut cells = Vec::new();
+        let mut height = 0;
+        let mut width = None;
+
+        for (r, line) in crate::lines(text).enumerate() {
+            let before = cells.len();
+            for (c, ch) in line.chars().enumerate() {
+                match cell(ch) {
+                    Ok(t) => cells.push(t),
+                    Err(err) => {
+                        return Err(Error::at_line(r + 1, line, err).with_column(c + 1).into())
+                    }
+                }
+            }
+
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let err = anyhow!("expected {} cells, got {}", width, len);
+                    return Err(Error::at_line(r + 1, line, err).into());
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            height,
+            width: width.unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (r, c): RC) -> bool {
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, rc: RC) -> Option<&T> {
+        self.contains(rc).then(|| &self.cells[self.offset(rc)])
+    }
+
+    pub fn get_mut(&mut self, rc: RC) -> Option<&mut T> {
+        let offset = self.offset(rc);
+        self.contains(rc).then(|| &mut self.cells[offset])
+    }
+
+    fn offset(&self, (r, c): RC) -> usize {
+        r * self.width + c
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = RC> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (RC, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, r: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[r * self.width..(r + 1) * self.width].iter()
+    }
+
+    pub fn col(&self, c: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[c..].iter().step_by(self.width)
+    }
+
+    /// The first position whose cell matches, row by row.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<RC> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(rc, _)| rc)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The position one step away, if it's still on the grid.
+    pub fn step(&self, (r, c): RC, (dr, dc): (isize, isize)) -> Option<RC> {
+        let rc = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(rc).then_some(rc)
+    }
+
+    /// The positions from `rc` (not including it) to the edge of the grid, in steps of `delta`.
+    pub fn ray(&self, rc: RC, delta: (isize, isize)) -> impl Iterator<Item = RC> + '_ {
+        std::iter::successors(self.step(rc, delta), move |&rc| self.step(rc, delta))
+    }
+
+    pub fn neighbors4(&self, rc: RC) -> impl Iterator<Item = RC> + '_ {
+        ORTHOGONAL.into_iter().filter_map(move |d| self.step(rc, d))
+    }
+
+    pub fn neighbors8(&self, rc: RC) -> impl Iterator<Item = RC> + '_ {
+        ALL_AROUND.into_iter().filter_map(move |d| self.step(rc, d))
+    }
+}
+
+impl<T> Index<RC> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, rc: RC) -> &Self::Output {
+        match self.get(rc) {
+            Some(t) => t,
+            None => panic!(
+                "{:?} is out of bounds for a {}x{} grid",
+                rc, self.height, self.width
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<RC> for Grid<T> {
+    fn index_mut(&mut self, rc: RC) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        match self.get_mut(rc) {
+            Some(t) => t,
+            None => panic!("{:?} is out of bounds for a {}x{} grid", rc, height, width),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in 0..self.height {
+            for t in self.row(r) {
+                write!(f, "{}", t)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+
+    const MAP: &str = "\
+#..
+.#.
+..#
+#.#
+";
+
+    fn parse() -> Grid<bool> {
+        Grid::parse(MAP, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            other => bail!("unexpected {:?}", other),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = parse();
+        assert_eq!((grid.height(), grid.width()), (4, 3));
+        assert!(grid[(1, 1)]);
+        assert!(!grid[(1, 2)]);
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        let chars = grid.map(|&wall| if wall { '#' } else { '.' });
+        assert_eq!(chars.to_string(), MAP);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("..\n.", |_| Ok(())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected 2 cells, got 1\n  | .");
+
+        let err = Grid::parse("..\n.?", |ch| match ch {
+            '.' => Ok(()),
+            other => bail!("unexpected {:?}", other),
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected '?'\n  | .?\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let grid = parse();
+        let walls = |it: &mut dyn Iterator<Item = &bool>| -> Vec<bool> { it.copied().collect() };
+
+        assert_eq!(walls(&mut grid.row(3)), vec![true, false, true]);
+        assert_eq!(walls(&mut grid.col(0)), vec![true, false, false, true]);
+        assert_eq!(
+            walls(&mut grid.col(2).rev()),
+            vec![true, true, false, false]
+        );
+
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((2, 0), (0, -1)).count(), 0);
+        assert_eq!(grid.position(|&wall| wall), Some((0, 0)));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = parse();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors8((3, 2)).collect::<Vec<_>>(),
+            vec![(2, 2), (3, 1), (2, 1)]
+        );
+    }
+}
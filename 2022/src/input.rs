@@ -0,0 +1,178 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    io::Read,
+    ops::Deref,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+use path_absolutize::Absolutize;
+
+/// Where `aoc fetch` puts the puzzle inputs (and where `aoc new` puts the examples).
+pub const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+/// The directory for one year's code, next to this one.
+pub fn year_dir(year: u32) -> PathBuf {
+    let dir = Path::new(INPUT_DIR).join(format!("../../{}", year));
+    match dir.absolutize() {
+        Ok(dir) => dir.to_path_buf(),
+        Err(_) => dir,
+    }
+}
+
+/// Every year's inputs live in an `input` directory next to its code, like this one's.
+pub fn input_dir(year: u32) -> PathBuf {
+    year_dir(year).join("input")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `input/dayNN.txt`
+    Real,
+    /// `input/dayNN-ex.txt`
+    Example,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "real" => Self::Real,
+            "ex" | "example" => Self::Example,
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Real => write!(f, "real"),
+            Source::Example => write!(f, "ex"),
+            Source::Stdin => write!(f, "-"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Source {
+    /// The file this source would be read from, if it's a file at all.
+    pub fn path(&self, dir: &Path, day: u32) -> Option<PathBuf> {
+        match self {
+            Source::Real => Some(dir.join(format!("day{:02}.txt", day))),
+            Source::Example => Some(dir.join(format!("day{:02}-ex.txt", day))),
+            Source::Stdin => None,
+            Source::Path(path) => Some(path.clone()),
+        }
+    }
+
+    pub fn read(&self, dir: &Path, day: u32) -> anyhow::Result<Input> {
+        let text = match self.path(dir, day) {
+            None => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .context("reading input from stdin")?;
+                text
+            }
+            Some(path) => {
+                if !path.exists() {
+                    let hint = match self {
+                        Source::Real => format!("run `aoc fetch <year> {}` to download it", day),
+                        _ => format!(
+                            "run `aoc new {}` to create it, then paste the input in",
+                            day
+                        ),
+                    };
+                    bail!("{} doesn't exist ({})", path.display(), hint);
+                }
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("reading {}", path.display()))?
+            }
+        };
+
+        if text.trim().is_empty() {
+            bail!("input from {} is empty", self);
+        }
+
+        Ok(Input {
+            source: self.clone(),
+            text,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub source: Source,
+    pub text: String,
+}
+
+impl Input {
+    /// Some puzzles use different parameters for the example than for the real input.
+    pub fn is_example(&self) -> bool {
+        self.source == Source::Example
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_source() {
+        assert_eq!(Source::from_str("real"), Ok(Source::Real));
+        assert_eq!(Source::from_str("ex"), Ok(Source::Example));
+        assert_eq!(Source::from_str("example"), Ok(Source::Example));
+        assert_eq!(Source::from_str("-"), Ok(Source::Stdin));
+        assert_eq!(
+            Source::from_str("/tmp/day01.txt"),
+            Ok(Source::Path("/tmp/day01.txt".into()))
+        );
+    }
+
+    #[test]
+    fn conventional_paths() {
+        let dir = Path::new("input");
+        assert_eq!(
+            Source::Real.path(dir, 7),
+            Some(PathBuf::from("input/day07.txt"))
+        );
+        assert_eq!(
+            Source::Example.path(dir, 17),
+            Some(PathBuf::from("input/day17-ex.txt"))
+        );
+        assert_eq!(Source::Stdin.path(dir, 1), None);
+    }
+
+    #[test]
+    fn other_years() {
+        assert_eq!(input_dir(2022), Path::new(INPUT_DIR));
+        assert!(input_dir(2018).ends_with("2018/input"));
+    }
+
+    #[test]
+    fn missing_file() {
+        let dir = Path::new("/nonexistent");
+        let err = Source::Real.read(dir, 3).unwrap_err();
+        assert!(
+            err.to_string().contains("/nonexistent/day03.txt"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, RangeInclusive, Sub},
+};
+
+/// Integers that can be the ends of an interval.
+pub trait Bound: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),+) => {$(
+        impl Bound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )+};
+}
+
+impl_bound!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A set of integers, stored as sorted, disjoint, non-adjacent ranges.
+///
+/// Ranges go in and come out inclusive (`start..=end`), like the puzzles describe them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // Half-open internally, so adjacent ranges are easy to spot: `a.1 == b.0`.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn half_open(range: RangeInclusive<T>) -> Option<(T, T)> {
+        let (start, end) = range.into_inner();
+        (start <= end).then(|| (start, end + T::ONE))
+    }
+
+    /// Adds every number in the range, merging with any ranges it touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let Some((mut start, mut end)) = Self::half_open(range) else {
+            return;
+        };
+
+        // Everything from `lo` to `hi` overlaps or touches the new range.
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+
+        if lo < hi {
+            start = start.min(self.ranges[lo].0);
+            end = end.max(self.ranges[hi - 1].1);
+        }
+        self.ranges.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Takes out every number in the range, splitting any range it cuts through.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let Some((start, end)) = Self::half_open(range) else {
+            return;
+        };
+
+        let lo = self.ranges.partition_point(|&(_, e)| e <= start);
+        let hi = self.ranges.partition_point(|&(s, _)| s < end);
+        if lo >= hi {
+            return;
+        }
+
+        let mut kept = Vec::with_capacity(2);
+        let (first_start, _) = self.ranges[lo];
+        let (_, last_end) = self.ranges[hi - 1];
+        if first_start < start {
+            kept.push((first_start, start));
+        }
+        if end < last_end {
+            kept.push((end, last_end));
+        }
+        self.ranges.splice(lo..hi, kept);
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e <= x);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= x)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many numbers are in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |n, &(s, e)| n + (e - s))
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=(e - T::ONE))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for r in other.ranges() {
+            set.insert(r);
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start < end {
+                ranges.push((start, end));
+            }
+
+            // Whichever range ends first can't overlap anything else.
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Everything within `bounds` that isn't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(bounds);
+        for r in self.ranges() {
+            set.remove(r);
+        }
+        set
+    }
+
+    /// The missing stretches within `bounds`, in order.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> {
+        let complement = self.complement(bounds);
+        complement.ranges.into_iter().map(|(s, e)| s..=(e - T::ONE))
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for r in iter {
+            set.insert(r);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut set: IntervalSet<i32> = [1..=3, 10..=12, 6..=7].into_iter().collect();
+        assert_eq!(ranges(&set), vec![1..=3, 6..=7, 10..=12]);
+        assert_eq!(set.len(), 8);
+
+        // Adjacent ranges merge, even without overlapping.
+        set.insert(4..=5);
+        assert_eq!(ranges(&set), vec![1..=7, 10..=12]);
+
+        set.insert(0..=20);
+        assert_eq!(ranges(&set), vec![0..=20]);
+
+        // Empty ranges don't add anything.
+        let (start, end) = (25, 24);
+        set.insert(start..=end);
+        assert_eq!(ranges(&set), vec![0..=20]);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        set.remove(3..=5);
+        assert_eq!(ranges(&set), vec![0..=2, 6..=10, 20..=30]);
+
+        set.remove(8..=25);
+        assert_eq!(ranges(&set), vec![0..=2, 6..=7, 26..=30]);
+
+        assert!(set.contains(7));
+        assert!(!set.contains(8));
+        assert!(!set.contains(-1));
+        assert!(set.contains(30));
+        assert!(!set.contains(31));
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        let a: IntervalSet<i32> = [0..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet<i32> = [3..=12].into_iter().collect();
+        assert_eq!(ranges(&a.intersection(&b)), vec![3..=5, 10..=12]);
+        assert_eq!(ranges(&a.union(&b)), vec![0..=15]);
+    }
+
+    #[test]
+    fn gaps() {
+        let set: IntervalSet<u32> = [2..=4, 8..=9].into_iter().collect();
+        assert_eq!(
+            set.gaps(0..=10).collect::<Vec<_>>(),
+            vec![0..=1, 5..=7, 10..=10]
+        );
+        assert_eq!(set.gaps(3..=9).collect::<Vec<_>>(), vec![5..=7]);
+        assert_eq!(set.complement(2..=4), IntervalSet::new());
+    }
+}
@@ -1,13 +1,40 @@
+pub mod answers;
+pub mod bitgrid;
+pub mod client;
+pub mod cycle;
+pub mod error;
+pub mod examples;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod scan;
+pub mod search;
+mod solution;
+pub mod submit;
+pub mod timing;
+
 use std::str::Split;
 
-pub fn lines(s: &str) -> Split<char> {
+pub use bitgrid::BitGrid;
+pub use error::Error;
+pub use grid::Grid;
+pub use input::Input;
+pub use intervals::IntervalSet;
+pub use point::{Heading, Point2, Point3};
+pub use solution::{Answer, Day, Part, Run, Solution};
+
+pub fn lines(s: &str) -> Split<'_, char> {
     s.trim_end().split('\n')
 }
 
-pub fn words(s: &str) -> Split<char> {
+pub fn words(s: &str) -> Split<'_, char> {
     s.split(' ')
 }
 
-pub fn blocks(s: &str) -> Split<&str> {
+pub fn blocks(s: &str) -> Split<'_, &str> {
     s.split("\n\n")
 }
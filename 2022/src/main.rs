@@ -0,0 +1,5 @@
+mod days;
+
+fn main() -> anyhow::Result<()> {
+    aoc::runner::main(2022, days::ALL)
+}
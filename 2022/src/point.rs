@@ -0,0 +1,242 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset in the plane.
+///
+/// Like a [`crate::Grid`] (and the puzzle text), `y` grows downward: north is `-y`, and turning
+/// right goes clockwise on the screen.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Element-wise arithmetic, plus scaling by a single coordinate.
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, k: T) -> Self {
+                Self { $($field: self.$field * k),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+/// Distances and signs only make sense for signed coordinates, so these are per-type.
+macro_rules! impl_signed {
+    ($($t:ty),+) => {$(
+        impl Point2<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// A step of at most one in each direction toward wherever this points.
+            pub fn signum(self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+
+            /// Turns 90° counterclockwise on the screen.
+            pub fn rotate_left(self) -> Self {
+                Self::new(self.y, -self.x)
+            }
+
+            /// Turns 90° clockwise on the screen.
+            pub fn rotate_right(self) -> Self {
+                Self::new(-self.y, self.x)
+            }
+        }
+
+        impl Point3<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                (self.x - other.x)
+                    .abs()
+                    .max((self.y - other.y).abs())
+                    .max((self.z - other.z).abs())
+            }
+
+            pub fn signum(self) -> Self {
+                Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+            }
+        }
+    )+};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// A compass direction in the plane, with north toward the top of the screen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// Clockwise from north.
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    pub fn turn_left(self) -> Self {
+        use Heading::*;
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        use Heading::*;
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn flip(self) -> Self {
+        use Heading::*;
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+
+    /// One step this way.
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Heading::North => (0, -1),
+            Heading::East => (1, 0),
+            Heading::South => (0, 1),
+            Heading::West => (-1, 0),
+        };
+        Point2::new(x.into(), y.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(3, -4);
+        let b = Point2::new(-1, 2);
+        assert_eq!(a + b, Point2::new(2, -2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a, Point2::new(-3, 4));
+        assert_eq!(a * 2, Point2::new(6, -8));
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 4);
+        assert_eq!(c, Point3::new(2, 3, 0));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::<i64>::new(3, -4);
+        assert_eq!(a.manhattan(Point2::default()), 7);
+        assert_eq!(a.chebyshev(Point2::default()), 4);
+        assert_eq!(a.signum(), Point2::new(1, -1));
+
+        let b = Point3::<i32>::new(1, -5, 2);
+        assert_eq!(b.manhattan(Point3::new(0, 0, 0)), 8);
+        assert_eq!(b.chebyshev(Point3::new(0, 0, 0)), 5);
+        assert_eq!(b.signum(), Point3::new(1, -1, 1));
+    }
+
+    #[test]
+    fn headings_and_rotations_agree() {
+        for h in Heading::ALL {
+            let d = h.delta::<i64>();
+            assert_eq!(h.turn_left().delta(), d.rotate_left());
+            assert_eq!(h.turn_right().delta(), d.rotate_right());
+            assert_eq!(h.flip().delta(), -d);
+            assert_eq!(h.turn_left().turn_right(), h);
+        }
+    }
+}
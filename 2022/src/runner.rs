@@ -0,0 +1,401 @@
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand};
+
+use crate::{
+    answers::{Answers, ANSWERS_FILE},
+    client::{self, Client, Config},
+    input::{input_dir, year_dir, Source},
+    scaffold,
+    solution::{Day, Part},
+    submit::{self, Log, Outcome},
+    timing::{Report, Timing},
+    Error,
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the answers for one day, or for every day if none is given.
+    Run {
+        day: Option<u32>,
+
+        /// Only run this part (1 or 2).
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// `real`, `ex`, `-` for stdin, or a path to an input file.
+        #[arg(long, default_value = "real")]
+        input: Source,
+    },
+
+    /// Time parsing and both parts for one day, or for every day if none is given.
+    Time {
+        day: Option<u32>,
+
+        /// `real`, `ex`, or a path to an input file.
+        #[arg(long, default_value = "real")]
+        input: Source,
+
+        /// Print the report as JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+
+        /// Also time every other year in the workspace (through `cargo run`), all in one report.
+        #[arg(long, conflicts_with = "day")]
+        all_years: bool,
+    },
+
+    /// Check answers against answers.toml, for one day or for every day if none is given.
+    Verify {
+        day: Option<u32>,
+
+        /// Only check this input (`real` or `ex`). By default, both are checked.
+        #[arg(long)]
+        input: Option<Source>,
+    },
+
+    /// Start a new day: generate its module, register it, and create its input files.
+    New { day: u32 },
+
+    /// Download a puzzle input into that year's input directory, unless it's already there.
+    Fetch { year: u32, day: u32 },
+
+    /// Send an answer for this year's puzzle. Without one, solve the real input and send that.
+    Submit {
+        day: u32,
+        part: Part,
+        answer: Option<String>,
+    },
+}
+
+/// The `aoc` command-line interface, for one year's calendar of solutions.
+pub fn main(year: u32, days: &[Day]) -> anyhow::Result<()> {
+    let dir = input_dir(year);
+
+    match Cli::parse().command {
+        Command::Run { day, part, input } => {
+            let days = select(days, day)?;
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            run(&dir, &days, &parts, &input)
+        }
+        Command::Time {
+            day,
+            input,
+            json,
+            all_years,
+        } => {
+            let days = select(days, day)?;
+            let mut report = time(year, &dir, &days, &input)?;
+            if all_years {
+                for other in other_years(year)? {
+                    report.merge(time_elsewhere(other, &input)?);
+                }
+            }
+            if json {
+                println!("{}", report.to_json());
+            } else {
+                print!("{}", report);
+            }
+            Ok(())
+        }
+        Command::Verify { day, input } => {
+            let days = select(days, day)?;
+            let sources = match input {
+                Some(source @ (Source::Real | Source::Example)) => vec![source],
+                Some(source) => bail!("no recorded answers for input {}", source),
+                None => vec![Source::Real, Source::Example],
+            };
+            let answers = Answers::load(Path::new(ANSWERS_FILE))?;
+            verify(year, &dir, &days, &sources, &answers)
+        }
+        Command::New { day } => new(year, day),
+        Command::Fetch { year, day } => {
+            let client = Client::new(&Config::load()?)?;
+            let path = client::fetch(&client, year, day, &input_dir(year))?;
+            println!("Saved {} day {} input to {}", year, day, path.display());
+            Ok(())
+        }
+        Command::Submit { day, part, answer } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => solve_real(&dir, &select(days, Some(day))?[0], part)?,
+            };
+            submit(year, &dir, day, part, &answer)
+        }
+    }
+}
+
+fn select(days: &[Day], number: Option<u32>) -> anyhow::Result<Vec<Day>> {
+    let Some(number) = number else {
+        return Ok(days.to_vec());
+    };
+
+    match days.iter().find(|d| d.number == number) {
+        Some(&day) => Ok(vec![day]),
+        None => bail!("no solution for day {}", number),
+    }
+}
+
+fn run(dir: &Path, days: &[Day], parts: &[Part], source: &Source) -> anyhow::Result<()> {
+    if days.len() > 1 && *source == Source::Stdin {
+        bail!("can't read more than one day's input from stdin");
+    }
+
+    let mut failures = 0;
+
+    for day in days {
+        let answers = source
+            .read(dir, day.number)
+            .and_then(|input| day.solve(&input, parts))
+            .map_err(|err| Error::from(err).with_day(day.number));
+
+        let answers = match answers {
+            Ok(run) => run.answers,
+            Err(err) => {
+                eprintln!("{:#}", err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for answer in answers {
+            match answer.value {
+                Ok(value) => println!("Day {} part {}: {}", day.number, answer.part, value),
+                Err(err) => {
+                    eprintln!("Day {} part {}: {:#}", day.number, answer.part, err);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{} failure(s)", failures);
+    }
+    Ok(())
+}
+
+fn new(year: u32, day: u32) -> anyhow::Result<()> {
+    for path in scaffold::new_day(&year_dir(year), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    // Without a session, the input can still be pasted in by hand.
+    let fetched = Config::load()
+        .and_then(|config| Client::new(&config))
+        .and_then(|client| client::fetch(&client, year, day, &input_dir(year)));
+    match fetched {
+        Ok(path) => println!("Saved the input to {}", path.display()),
+        Err(err) => {
+            eprintln!("Didn't download the input: {:#}", err);
+            if let Some(path) = scaffold::empty_input(&input_dir(year), &Source::Real, day)? {
+                println!("Wrote {} (empty, paste the input in)", path.display());
+            }
+        }
+    }
+
+    println!("Next: cargo run -- run {} --input ex", day);
+    Ok(())
+}
+
+fn solve_real(dir: &Path, day: &Day, part: Part) -> anyhow::Result<String> {
+    let input = Source::Real.read(dir, day.number)?;
+    let mut run = day
+        .solve(&input, &[part])
+        .map_err(|err| Error::from(err).with_day(day.number))?;
+
+    let answer = run.answers.remove(0).value?;
+    println!("Day {} part {}: {}", day.number, part, answer);
+    Ok(answer)
+}
+
+fn submit(year: u32, dir: &Path, day: u32, part: Part, answer: &str) -> anyhow::Result<()> {
+    let client = Client::new(&Config::load()?)?;
+    let mut log = Log::load(&submit::log_path(dir))?;
+
+    let response = submit::submit(&client, &mut log, year, day, part, answer, submit::now())?;
+    println!("{}", response.message);
+
+    match response.outcome {
+        Outcome::Correct => {
+            println!("Record it in answers.toml under [{}.{}.real]", year, day);
+            Ok(())
+        }
+        outcome => bail!("not accepted: {:?}", outcome),
+    }
+}
+
+/// Solves every day, collecting timings. Failures are reported but still show up in the table.
+fn time(year: u32, dir: &Path, days: &[Day], source: &Source) -> anyhow::Result<Report> {
+    if days.len() > 1 && *source == Source::Stdin {
+        bail!("can't read more than one day's input from stdin");
+    }
+
+    let mut report = Report::default();
+
+    for day in days {
+        let mut timing = Timing {
+            year,
+            day: day.number,
+            parse: None,
+            part1: None,
+            part2: None,
+        };
+
+        let run = source
+            .read(dir, day.number)
+            .and_then(|input| day.solve(&input, &Part::BOTH))
+            .map_err(|err| Error::from(err).with_day(day.number));
+
+        match run {
+            Ok(run) => {
+                timing.parse = Some(run.parse);
+                for answer in run.answers {
+                    if let Err(err) = answer.value {
+                        eprintln!("Day {} part {}: {:#}", day.number, answer.part, err);
+                        continue;
+                    }
+                    match answer.part {
+                        Part::One => timing.part1 = Some(answer.elapsed),
+                        Part::Two => timing.part2 = Some(answer.elapsed),
+                    }
+                }
+            }
+            Err(err) => eprintln!("{:#}", err),
+        }
+
+        report.push(timing);
+    }
+
+    Ok(report)
+}
+
+/// The other years with solutions in this workspace: directories named after a year, with a crate
+/// in them.
+fn other_years(year: u32) -> anyhow::Result<Vec<u32>> {
+    let workspace = year_dir(year).join("..");
+    let entries = std::fs::read_dir(&workspace)
+        .with_context(|| format!("reading {}", workspace.display()))?;
+
+    let mut years = vec![];
+    for entry in entries {
+        let entry = entry?;
+        let Some(other) = entry.file_name().to_str().and_then(|n| n.parse().ok()) else {
+            continue;
+        };
+        if other != year && entry.path().join("Cargo.toml").exists() {
+            years.push(other);
+        }
+    }
+    years.sort();
+    Ok(years)
+}
+
+/// Times another year's solutions by running its own binary, which has its own calendar.
+fn time_elsewhere(year: u32, source: &Source) -> anyhow::Result<Report> {
+    if !matches!(source, Source::Real | Source::Example) {
+        bail!("can't time every year with input {}", source);
+    }
+
+    let manifest = year_dir(year).join("Cargo.toml");
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut command = std::process::Command::new(cargo);
+    command
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(&manifest);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(["--", "time", "--json", "--input", &source.to_string()]);
+
+    let output = command
+        .stderr(std::process::Stdio::inherit())
+        .output()
+        .with_context(|| format!("running {}'s solutions", year))?;
+    if !output.status.success() {
+        bail!("timing {} failed ({})", year, output.status);
+    }
+
+    let json = String::from_utf8(output.stdout)?;
+    Report::from_json(&json).with_context(|| format!("reading {}'s timing report", year))
+}
+
+/// Runs every day against the recorded answers. Only failures (wrong answers or errors) are fatal.
+fn verify(
+    year: u32,
+    dir: &Path,
+    days: &[Day],
+    sources: &[Source],
+    answers: &Answers,
+) -> anyhow::Result<()> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        for source in sources {
+            let label = format!("Day {} {}", day.number, source);
+
+            if !source
+                .path(dir, day.number)
+                .is_some_and(|path| path.exists())
+            {
+                println!("{}: missing (no input)", label);
+                missing += 1;
+                continue;
+            }
+
+            let run = source.read(dir, day.number);
+            let run = match run.and_then(|input| day.solve(&input, &Part::BOTH)) {
+                Ok(run) => run,
+                Err(err) => {
+                    println!("{}: fail ({:#})", label, err);
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for answer in run.answers {
+                let expected = answers.get(year, day.number, source, answer.part);
+                match (answer.value, expected) {
+                    (Err(err), _) => {
+                        println!("{} part {}: fail ({:#})", label, answer.part, err);
+                        failed += 1;
+                    }
+                    (Ok(got), None) => {
+                        println!("{} part {}: missing (got {})", label, answer.part, got);
+                        missing += 1;
+                    }
+                    (Ok(got), Some(expected)) if got.trim() == expected.trim() => {
+                        println!("{} part {}: pass", label, answer.part);
+                        passed += 1;
+                    }
+                    (Ok(got), Some(expected)) => {
+                        println!(
+                            "{} part {}: fail (got {}, expected {})",
+                            label, answer.part, got, expected
+                        );
+                        failed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        bail!("{} failure(s)", failed);
+    }
+    Ok(())
+}
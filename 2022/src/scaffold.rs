@@ -0,0 +1,189 @@
+//! Starting a new day: the module, its place in the calendar, and empty input files.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use crate::input::Source;
+
+const TEMPLATE: &str = include_str!("days/dayXX.rs.tmpl");
+
+/// The template, filled in for one day.
+pub fn render(day: u32) -> String {
+    TEMPLATE
+        .replace("DAY: u32 = XX", &format!("DAY: u32 = {}", day))
+        .replace("XX", &format!("{:02}", day))
+}
+
+/// Adds the day to `days/mod.rs`: a `mod` line and an entry in `ALL`, both in order.
+pub fn register(mod_rs: &str, day: u32) -> anyhow::Result<String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("mod {};", module);
+    let entry = format!("    Day::of::<{}::Day{:02}>(),", module, day);
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    if lines.contains(&mod_line) {
+        bail!("{} is already registered", module);
+    }
+
+    insert_sorted(&mut lines, mod_line, |line| line.starts_with("mod day"))
+        .context("no `mod dayNN;` lines to add to")?;
+    insert_sorted(&mut lines, entry, |line| {
+        line.trim_start().starts_with("Day::of::<day")
+    })
+    .context("no `Day::of` entries to add to")?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Puts the line in its place among the (already sorted) lines that `matches`.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    line: String,
+    matches: impl Fn(&str) -> bool,
+) -> Option<()> {
+    let first = lines.iter().position(|l| matches(l))?;
+    let count = lines[first..].iter().take_while(|l| matches(l)).count();
+
+    let offset = lines[first..first + count].partition_point(|l| *l < line);
+    lines.insert(first + offset, line);
+    Some(())
+}
+
+/// Creates the day's module (registered in `days/mod.rs`) and its empty example input. Nothing is
+/// overwritten: if the module exists already, this doesn't touch anything.
+pub fn new_day(year_dir: &Path, day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("there's no day {} (expected 1 to 25)", day);
+    }
+
+    let days_dir = year_dir.join("src/days");
+    let module = days_dir.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+
+    let mod_rs = days_dir.join("mod.rs");
+    let registry = std::fs::read_to_string(&mod_rs)
+        .with_context(|| format!("reading {}", mod_rs.display()))?;
+    let registry =
+        register(&registry, day).with_context(|| format!("updating {}", mod_rs.display()))?;
+
+    let input_dir = year_dir.join("input");
+    std::fs::create_dir_all(&input_dir)
+        .with_context(|| format!("creating {}", input_dir.display()))?;
+
+    std::fs::write(&module, render(day))
+        .with_context(|| format!("writing {}", module.display()))?;
+    std::fs::write(&mod_rs, registry).with_context(|| format!("writing {}", mod_rs.display()))?;
+
+    let mut created = vec![module, mod_rs];
+    created.extend(empty_input(&input_dir, &Source::Example, day)?);
+    Ok(created)
+}
+
+/// Creates an empty input file to paste into, unless there's one there already. Returns the path
+/// if it had to be created.
+pub fn empty_input(input_dir: &Path, source: &Source, day: u32) -> anyhow::Result<Option<PathBuf>> {
+    let Some(path) = source.path(input_dir, day) else {
+        bail!("{:?} input doesn't come from a file", source);
+    };
+    if path.exists() {
+        return Ok(None);
+    }
+    std::fs::write(&path, "").with_context(|| format!("writing {}", path.display()))?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::scratch_dir;
+
+    const MOD_RS: &str = "\
+use aoc::Day;
+
+mod day01;
+mod day03;
+
+pub const ALL: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        let registered = register(MOD_RS, 2).unwrap();
+        assert_eq!(
+            registered,
+            "\
+use aoc::Day;
+
+mod day01;
+mod day02;
+mod day03;
+
+pub const ALL: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+];
+"
+        );
+
+        assert!(register(&registered, 2).is_err());
+        assert!(register(MOD_RS, 25)
+            .unwrap()
+            .contains("mod day03;\nmod day25;\n"));
+    }
+
+    #[test]
+    fn renders_the_template() {
+        let module = render(7);
+        assert!(module.contains("pub struct Day07 {"));
+        assert!(module.contains("const DAY: u32 = 7;"));
+        assert!(module.contains("fn part1(_lines: &[String])"));
+        assert!(module.contains("input/day07-ex.txt"));
+        assert!(module.contains("aoc::example_tests! {\n    Day07,"));
+        assert!(!module.contains("XX"));
+    }
+
+    #[test]
+    fn never_overwrites() {
+        let dir = scratch_dir("scaffold");
+        std::fs::create_dir_all(dir.join("src/days")).unwrap();
+        std::fs::write(dir.join("src/days/mod.rs"), MOD_RS).unwrap();
+
+        let created = new_day(&dir, 2).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                dir.join("src/days/day02.rs"),
+                dir.join("src/days/mod.rs"),
+                dir.join("input/day02-ex.txt"),
+            ]
+        );
+
+        std::fs::write(dir.join("src/days/day02.rs"), "// solved!").unwrap();
+        assert!(new_day(&dir, 2).is_err());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("src/days/day02.rs")).unwrap(),
+            "// solved!"
+        );
+
+        assert!(new_day(&dir, 26).is_err());
+
+        let real = dir.join("input/day02.txt");
+        assert_eq!(
+            empty_input(&dir.join("input"), &Source::Real, 2).unwrap(),
+            Some(real.clone())
+        );
+        std::fs::write(&real, "1 2 3").unwrap();
+        assert_eq!(
+            empty_input(&dir.join("input"), &Source::Real, 2).unwrap(),
+            None
+        );
+        assert_eq!(std::fs::read_to_string(real).unwrap(), "1 2 3");
+    }
+}
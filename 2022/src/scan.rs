@@ -0,0 +1,184 @@
+//! Pulling typed fields out of lines that all follow the same pattern.
+//!
+//! A pattern is the line as written, with `{}` wherever a field goes (like `format!` in reverse).
+//! Each field runs up to the next piece of literal text, so fields can't be directly next to each
+//! other. Use the [`scan!`](crate::scan!) macro to parse the fields as they come out:
+//!
+//! ```
+//! let (x, y) = aoc::scan!("x=3, y=-4", "x={}, y={}", i32, i32).unwrap();
+//! assert_eq!((x, y), (3, -4));
+//!
+//! let err = aoc::scan!("x=3, q=-4", "x={}, y={}", i32, i32).unwrap_err();
+//! assert_eq!(err.to_string(), r#"column 3: expected ", y=" somewhere in "3, q=-4""#);
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+/// Where and why a line didn't match its pattern. Columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// The text of one field, and the column it started at.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl Field<'_> {
+    pub fn parse<T>(self) -> Result<T, Mismatch>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|err| Mismatch {
+            column: self.column,
+            message: format!(
+                "can't parse {:?} as {}: {}",
+                self.text,
+                std::any::type_name::<T>(),
+                err
+            ),
+        })
+    }
+}
+
+/// Splits the line into the fields of the pattern, making sure everything else matches exactly.
+///
+/// Panics if the pattern doesn't have `count` fields, since that's a bug in the pattern rather than
+/// a problem with the input.
+pub fn fields<'a>(line: &'a str, pattern: &str, count: usize) -> Result<Vec<Field<'a>>, Mismatch> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    assert_eq!(
+        literals.len() - 1,
+        count,
+        "pattern {:?} should have {} fields",
+        pattern,
+        count
+    );
+
+    let column = |pos: usize| line[..pos].chars().count() + 1;
+    let mismatch = |pos: usize, message: String| Mismatch {
+        column: column(pos),
+        message,
+    };
+
+    let (first, rest) = literals.split_first().unwrap();
+    if !line.starts_with(first) {
+        // Point at where they stop agreeing, not just the start of the line.
+        let same = line
+            .char_indices()
+            .zip(first.chars())
+            .find(|&((_, a), b)| a != b)
+            .map_or(line.len(), |((i, _), _)| i);
+        let found = line.get(same..first.len()).unwrap_or(&line[same..]);
+        return Err(mismatch(
+            same,
+            format!("expected {:?}, found {:?}", &first[same..], found),
+        ));
+    }
+
+    let mut pos = first.len();
+    let mut fields = Vec::with_capacity(count);
+    for (i, literal) in rest.iter().enumerate() {
+        let remaining = &line[pos..];
+        let last = i == rest.len() - 1;
+
+        let len = if last && literal.is_empty() {
+            // The last field takes whatever's left.
+            remaining.len()
+        } else if literal.is_empty() {
+            panic!("pattern {:?} has fields with nothing between them", pattern);
+        } else {
+            match remaining.find(literal) {
+                Some(len) => len,
+                None => {
+                    return Err(mismatch(
+                        pos,
+                        format!("expected {:?} somewhere in {:?}", literal, remaining),
+                    ))
+                }
+            }
+        };
+
+        fields.push(Field {
+            column: column(pos),
+            text: &remaining[..len],
+        });
+        pos += len + literal.len();
+    }
+
+    if pos < line.len() {
+        return Err(mismatch(
+            pos,
+            format!("expected the end of the line, found {:?}", &line[pos..]),
+        ));
+    }
+
+    Ok(fields)
+}
+
+/// Matches a line against a pattern, and parses each field as the corresponding type.
+///
+/// With one type, this gives back the one value. With more, it gives back a tuple of them.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $t:ty $(,)?) => {
+        $crate::scan::fields($line, $pattern, 1).and_then(|fields| fields[0].parse::<$t>())
+    };
+    ($line:expr, $pattern:expr, $($t:ty),+ $(,)?) => {
+        $crate::scan::fields($line, $pattern, [$(stringify!($t)),+].len()).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            Ok(($(fields.next().unwrap().parse::<$t>()?,)+))
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fields_and_types() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        let pattern = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+        assert_eq!(
+            scan!(line, pattern, i32, i32, i32, i32),
+            Ok((2, -18, -2, 15))
+        );
+
+        assert_eq!(scan!("Monkey 3:", "Monkey {}:", usize), Ok(3));
+        assert_eq!(
+            scan!("a -> b", "{} -> {}", String, char),
+            Ok(("a".to_string(), 'b'))
+        );
+    }
+
+    #[test]
+    fn mismatches() {
+        let err = scan!("Monkey three:", "Monkey {}:", usize).unwrap_err();
+        assert_eq!(err.column, 8);
+        assert_eq!(
+            err.message,
+            r#"can't parse "three" as usize: invalid digit found in string"#
+        );
+
+        let err = scan!("Monkee 3:", "Monkey {}:", usize).unwrap_err();
+        assert_eq!(err.to_string(), r#"column 6: expected "y ", found "e ""#);
+
+        let err = scan!("Monkey 3: ok", "Monkey {}:", usize).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"column 10: expected the end of the line, found " ok""#
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Shortest-path searches over implicit graphs.
+//!
+//! Nodes are never listed up front: each search only needs a `successors` function for the
+//! node it's looking at and an `is_goal` test. Every search can start from several nodes at once,
+//! which finds the nearest start without a separate search from each one.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest way to a goal: the total cost, and every node along the way (both ends included).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue: VecDeque<(N, usize)> = VecDeque::new();
+
+    for start in starts {
+        if parents.insert(start.clone(), None).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from the starts (including the starts themselves).
+pub fn reachable<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    seen
+}
+
+/// Dijkstra's algorithm, for steps with different (non-negative) costs.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. The heuristic must never overestimate the remaining cost, or the path found might
+/// not be the cheapest one.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut best: HashMap<N, C> = HashMap::new();
+    let mut done: HashSet<N> = HashSet::new();
+    let mut queue: BinaryHeap<Reverse<Entry<N, C>>> = BinaryHeap::new();
+
+    for start in starts {
+        let cost = C::default();
+        if best.insert(start.clone(), cost).is_none() {
+            parents.insert(start.clone(), None);
+            let estimate = heuristic(&start);
+            queue.push(Reverse(Entry {
+                estimate,
+                cost,
+                node: start,
+            }));
+        }
+    }
+
+    while let Some(Reverse(Entry { cost, node, .. })) = queue.pop() {
+        if !done.insert(node.clone()) {
+            // Already expanded through a cheaper path.
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            if best.get(&next).is_some_and(|&old| old <= cost) {
+                continue;
+            }
+
+            best.insert(next.clone(), cost);
+            parents.insert(next.clone(), Some(node.clone()));
+            queue.push(Reverse(Entry {
+                estimate: cost + heuristic(&next),
+                cost,
+                node: next,
+            }));
+        }
+    }
+
+    None
+}
+
+/// A node waiting in the queue, ordered by estimated total cost.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates, prefer whichever has come further: it's probably closer to a goal.
+        self.estimate
+            .cmp(&other.estimate)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3, with a cheaper detour through 2 when costs count.
+    fn edges(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 10)],
+            2 => vec![(4, 1)],
+            4 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let path = bfs([0], |n| edges(n).into_iter().map(|(m, _)| m), |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn dijkstra_counts_costs() {
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec![0, 2, 4, 3]);
+        assert_eq!((path.start(), path.goal()), (&0, &3));
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let path = dijkstra([0, 4], edges, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, vec![4, 3]);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(dijkstra([3], edges, |&n| n == 0), None);
+        assert_eq!(
+            reachable([2], |n| edges(n).into_iter().map(|(m, _)| m)),
+            HashSet::from([2, 4, 3])
+        );
+    }
+
+    #[test]
+    fn astar_on_a_line() {
+        let goal = 20i64;
+        let path = astar(
+            [0i64],
+            |&n| [(n - 1, 1), (n + 1, 1)],
+            |&n| (goal - n).abs(),
+            |&n| n == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 20);
+        assert_eq!(path.nodes, (0..=20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,108 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+use crate::{input::Input, timing::time, Error};
+
+/// One day's puzzle: parse the input once, then answer both parts from the parsed form.
+pub trait Solution: Sized {
+    const DAY: u32;
+
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &Input) -> anyhow::Result<Self>;
+    fn part1(&self) -> anyhow::Result<Self::Answer1>;
+    fn part2(&self) -> anyhow::Result<Self::Answer2>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => bail!("no such part: {:?} (expected 1 or 2)", other),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: anyhow::Result<String>,
+    pub elapsed: Duration,
+}
+
+/// Everything that came out of solving one day's input.
+#[derive(Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A [`Solution`] with its types erased, so a whole calendar of them fits in one slice.
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    pub number: u32,
+    solve: fn(&Input, &[Part]) -> anyhow::Result<Run>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parses the input and answers the requested parts, in order.
+    ///
+    /// A parse error fails the whole day, but each part succeeds or fails on its own.
+    pub fn solve(&self, input: &Input, parts: &[Part]) -> anyhow::Result<Run> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &Input, parts: &[Part]) -> anyhow::Result<Run> {
+    let (solution, parse) = time(|| S::parse(input));
+    let solution = solution.map_err(|err| Error::from(err).with_day(S::DAY))?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let (value, elapsed) = time(|| match part {
+                Part::One => solution.part1().map(|a| a.to_string()),
+                Part::Two => solution.part2().map(|a| a.to_string()),
+            });
+            Answer {
+                part,
+                value,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(Run { parse, answers })
+}
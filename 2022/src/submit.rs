@@ -0,0 +1,401 @@
+//! Sending answers to the website, and remembering how it went so we don't send the same wrong
+//! answer twice (or get locked out for sending too fast).
+
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, solution::Part};
+
+/// What the site said about an answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Not checked at all, because the last answer was too recent.
+    TooSoon,
+    /// Not checked at all, usually because this part is already solved.
+    WrongLevel,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// The interesting parts of the page that comes back from submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long (in seconds) until the site will take another answer, if it said.
+    pub wait: Option<u64>,
+    /// The text of the message, minus the HTML.
+    pub message: String,
+}
+
+impl Response {
+    pub fn parse(html: &str) -> anyhow::Result<Self> {
+        let message = article_text(html);
+
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if message.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if message.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            bail!("unrecognized response: {:?}", message);
+        };
+
+        Ok(Self {
+            outcome,
+            wait: wait_seconds(&message),
+            message,
+        })
+    }
+}
+
+/// The result message is the only `<article>` on the page.
+fn article_text(html: &str) -> String {
+    let start = html.find("<article>").map_or(0, |i| i + "<article>".len());
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |i| start + i);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Finds "You have 1m 30s left to wait" or "please wait 5 minutes before trying again".
+fn wait_seconds(message: &str) -> Option<u64> {
+    if let Some(end) = message.find(" left to wait") {
+        let start = message[..end].rfind("You have ")? + "You have ".len();
+        let mut seconds = 0;
+        for amount in message[start..end].split(' ') {
+            let (n, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let n: u64 = n.parse().ok()?;
+            seconds += match unit {
+                "h" => n * 60 * 60,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+        }
+        return Some(seconds);
+    }
+
+    let start = message.find("please wait ")? + "please wait ".len();
+    let mut words = message[start..].split(' ');
+    let n = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        "minute" | "minutes" => Some(n * 60),
+        "second" | "seconds" => Some(n),
+        _ => None,
+    }
+}
+
+/// One answer sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    /// No more answers until this time (seconds since the Unix epoch).
+    pub wait_until: Option<u64>,
+}
+
+/// Where the attempt log goes. Like the inputs, it's specific to one account.
+pub fn log_path(input_dir: &Path) -> PathBuf {
+    input_dir.join("submissions.jsonl")
+}
+
+/// Every answer ever sent (from this checkout), one JSON object per line.
+#[derive(Debug, Clone)]
+pub struct Log {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Log {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = if path.exists() {
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?
+        } else {
+            String::new()
+        };
+
+        let attempts = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("{} line {}", path.display(), i + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    fn attempts(&self, year: u32, day: u32, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Refuses answers that can't possibly be right, or that the site won't look at yet.
+    pub fn check(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> anyhow::Result<()> {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            bail!("the answer is empty");
+        }
+
+        let number: Option<i128> = answer.parse().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            if attempt.outcome == Outcome::Correct {
+                bail!("already solved: the answer was {}", attempt.answer);
+            }
+
+            if attempt.outcome.is_wrong() && attempt.answer == answer {
+                bail!("already tried {}: {:?}", answer, attempt.outcome);
+            }
+
+            let bound: Option<i128> = attempt.answer.parse().ok();
+            match (attempt.outcome, number, bound) {
+                (Outcome::TooHigh, Some(n), Some(high)) if n >= high => {
+                    bail!("{} can't be right: {} was already too high", n, high)
+                }
+                (Outcome::TooLow, Some(n), Some(low)) if n <= low => {
+                    bail!("{} can't be right: {} was already too low", n, low)
+                }
+                _ => {}
+            }
+        }
+
+        // The cooldown is for the whole account, not just this puzzle.
+        let wait_until = self.attempts.iter().filter_map(|a| a.wait_until).max();
+        if let Some(wait_until) = wait_until.filter(|&t| t > now) {
+            bail!(
+                "the site won't take another answer for {}s",
+                wait_until - now
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("opening {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&attempt)?)
+            .with_context(|| format!("writing {}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Checks the answer against the log, sends it, and records what happened.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> anyhow::Result<Response> {
+    let answer = answer.trim();
+    log.check(year, day, part, answer, now)?;
+
+    let html = client.answer(year, day, part, answer)?;
+    let response = Response::parse(&html)?;
+
+    log.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: response.outcome,
+        at: now,
+        wait_until: response.wait.map(|wait| now + wait),
+    })?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{scratch_dir, MockServer};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn responses() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(Response::parse(&correct).unwrap().outcome, Outcome::Correct);
+
+        let low = page("That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data. <a href=\"/2022/day/1\">[Return to Day 1]</a> please wait one minute before trying again.");
+        let low = Response::parse(&low).unwrap();
+        assert_eq!((low.outcome, low.wait), (Outcome::TooLow, Some(60)));
+
+        let wrong = page("That's not the right answer.  Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.");
+        let wrong = Response::parse(&wrong).unwrap();
+        assert_eq!((wrong.outcome, wrong.wait), (Outcome::Wrong, Some(300)));
+
+        let soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        let soon = Response::parse(&soon).unwrap();
+        assert_eq!((soon.outcome, soon.wait), (Outcome::TooSoon, Some(65)));
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(
+            Response::parse(&level).unwrap().outcome,
+            Outcome::WrongLevel
+        );
+
+        assert!(Response::parse(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn history_rules_out_answers() {
+        let dir = scratch_dir("history");
+        let mut log = Log::load(&log_path(&dir)).unwrap();
+
+        let mut attempt = |answer: &str, outcome, wait_until| {
+            log.record(Attempt {
+                year: 2022,
+                day: 1,
+                part: Part::One,
+                answer: answer.to_string(),
+                outcome,
+                at: 100,
+                wait_until,
+            })
+            .unwrap();
+        };
+        attempt("500", Outcome::TooHigh, None);
+        attempt("100", Outcome::TooLow, Some(160));
+        attempt("abc", Outcome::Wrong, None);
+
+        let log = Log::load(&log_path(&dir)).unwrap();
+        let check = |answer: &str, now| log.check(2022, 1, Part::One, answer, now);
+
+        assert!(check("600", 200)
+            .unwrap_err()
+            .to_string()
+            .contains("too high"));
+        assert!(check("500", 200).is_err());
+        assert!(check("99", 200)
+            .unwrap_err()
+            .to_string()
+            .contains("too low"));
+        assert!(check("abc", 200)
+            .unwrap_err()
+            .to_string()
+            .contains("already tried"));
+        assert!(check("", 200).is_err());
+        assert!(check("300", 150).unwrap_err().to_string().contains("10s"));
+        check("300", 200).unwrap();
+
+        // Other puzzles have their own bounds.
+        log.check(2022, 1, Part::Two, "600", 200).unwrap();
+    }
+
+    #[test]
+    fn submit_to_server() {
+        let server = MockServer::start(|req| {
+            let message = match req.body.as_str() {
+                "level=1&answer=24000" => "That's the right answer!",
+                _ => "That's not the right answer; your answer is too high.",
+            };
+            (200, page(message))
+        });
+        let client = Client::new(&server.config()).unwrap();
+        let dir = scratch_dir("submit");
+        let mut log = Log::load(&log_path(&dir)).unwrap();
+
+        let response = submit(&client, &mut log, 2022, 1, Part::One, "99999", 1000).unwrap();
+        assert_eq!(response.outcome, Outcome::TooHigh);
+
+        // Never sent, because the log already knows it's too high.
+        assert!(submit(&client, &mut log, 2022, 1, Part::One, "100000", 1000).is_err());
+
+        let response = submit(&client, &mut log, 2022, 1, Part::One, "24000\n", 1000).unwrap();
+        assert_eq!(response.outcome, Outcome::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].url, "/2022/day/1/answer");
+        assert_eq!(requests[1].cookie.as_deref(), Some("session=cookie"));
+
+        let log = Log::load(&log_path(&dir)).unwrap();
+        let outcomes: Vec<Outcome> = log.attempts.iter().map(|a| a.outcome).collect();
+        assert_eq!(outcomes, vec![Outcome::TooHigh, Outcome::Correct]);
+        assert!(log.check(2022, 1, Part::One, "1", 1000).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Runs `f` and measures how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let t = f();
+    (t, start.elapsed())
+}
+
+/// Wall-clock times for one day. A missing time means that step failed (or doesn't exist).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    #[serde(rename = "parse_ms", with = "millis")]
+    pub parse: Option<Duration>,
+    #[serde(rename = "part1_ms", with = "millis")]
+    pub part1: Option<Duration>,
+    #[serde(rename = "part2_ms", with = "millis")]
+    pub part2: Option<Duration>,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        [self.parse, self.part1, self.part2].iter().flatten().sum()
+    }
+}
+
+/// Durations in JSON are (fractional) milliseconds.
+mod millis {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match d {
+            Some(d) => s.serialize_some(&(d.as_secs_f64() * 1000.0)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        let ms: Option<f64> = Option::deserialize(d)?;
+        Ok(ms.map(|ms| Duration::from_secs_f64(ms / 1000.0)))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn push(&mut self, timing: Timing) {
+        self.timings.push(timing);
+    }
+
+    /// Adds another report's timings (say, for another year), keeping them in order.
+    pub fn merge(&mut self, other: Report) {
+        self.timings.extend(other.timings);
+        self.timings.sort_by_key(|t| (t.year, t.day));
+    }
+
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(Timing::total).sum()
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn cell(d: Option<Duration>) -> String {
+            match d {
+                Some(d) => format!("{:.1?}", d),
+                None => "-".to_string(),
+            }
+        }
+
+        writeln!(
+            f,
+            "{:>4} {:>3} {:>10} {:>10} {:>10} {:>10}",
+            "Year", "Day", "Parse", "Part 1", "Part 2", "Total"
+        )?;
+
+        for t in &self.timings {
+            writeln!(
+                f,
+                "{:>4} {:>3} {:>10} {:>10} {:>10} {:>10}",
+                t.year,
+                t.day,
+                cell(t.parse),
+                cell(t.part1),
+                cell(t.part2),
+                cell(Some(t.total())),
+            )?;
+        }
+
+        writeln!(f, "{:>52}", cell(Some(self.total())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totals_skip_missing_parts() {
+        let timing = Timing {
+            year: 2022,
+            day: 25,
+            parse: Some(Duration::from_millis(1)),
+            part1: Some(Duration::from_millis(2)),
+            part2: None,
+        };
+        assert_eq!(timing.total(), Duration::from_millis(3));
+
+        let report = Report {
+            timings: vec![timing.clone(), timing],
+        };
+        assert_eq!(report.total(), Duration::from_millis(6));
+    }
+
+    #[test]
+    fn json() {
+        let report = Report {
+            timings: vec![Timing {
+                year: 2018,
+                day: 1,
+                parse: None,
+                part1: Some(Duration::from_micros(1500)),
+                part2: Some(Duration::from_millis(2)),
+            }],
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "timings": [{
+                    "year": 2018,
+                    "day": 1,
+                    "parse_ms": null,
+                    "part1_ms": 1.5,
+                    "part2_ms": 2.0,
+                }]
+            })
+        );
+        assert_eq!(Report::from_json(&report.to_json()).unwrap(), report);
+    }
+
+    #[test]
+    fn merge_years() {
+        let timing = |year, day| Timing {
+            year,
+            day,
+            parse: None,
+            part1: None,
+            part2: None,
+        };
+
+        let mut report = Report {
+            timings: vec![timing(2022, 1), timing(2022, 2)],
+        };
+        report.merge(Report {
+            timings: vec![timing(2018, 1), timing(2018, 5)],
+        });
+
+        let days: Vec<(u32, u32)> = report.timings.iter().map(|t| (t.year, t.day)).collect();
+        assert_eq!(days, vec![(2018, 1), (2018, 5), (2022, 1), (2022, 2)]);
+    }
+}